@@ -7,17 +7,26 @@ import type {
   ModuleContext,
   ModuleExports,
   ModuleId,
+  ModuleMeta,
 } from './types';
 import * as utils from './utils';
 
 export function createGlobalModule(): GlobalModule {
   const moduleRegistry = new Map<ModuleId, Module>();
+  const urlRegistry = new Map<ModuleId, string>();
 
   function __exports(
     exports: Exports,
     definitions: () => Record<string, unknown>,
+    sources: Exports[],
   ): void {
     utils.copyProps(exports, definitions());
+
+    // Namespace exports of the sources (re-export all).
+    // Copied after the definitions, so the explicit exports take precedence.
+    if (sources.length) {
+      utils.copyProps(exports, toNamespaceExports(...sources));
+    }
   }
 
   function require(id: ModuleId): Exports {
@@ -38,28 +47,84 @@ export function createGlobalModule(): GlobalModule {
     return module;
   }
 
-  function toNamespaceExports(exports: Exports): Exports {
+  function registerUrl(id: ModuleId, url: string | URL): void {
+    urlRegistry.set(id, String(url));
+  }
+
+  function url(id: ModuleId): URL {
+    const registeredUrl = urlRegistry.get(id);
+
+    if (registeredUrl == null) {
+      throw new Error(`url not found: '${id}'`);
+    }
+
+    // New instance for each reference (same as `new URL(src, import.meta.url)`).
+    return new URL(registeredUrl);
+  }
+
+  function toNamespaceExports(...sources: Exports[]): Exports {
     const nsExports = createExports();
+    const sourceOf = new Map<string, Exports>();
 
     // In the case of namespace exports (re-export all), the `default` field must be excluded.
-    utils.copyProps(nsExports, exports, 'default');
+    const ambiguousKeys = new Set(['default']);
+
+    // A name that is exported by more than one source is ambiguous and must be excluded,
+    // unless all of them refer to the same origin (eg. re-exported through different paths).
+    for (const source of sources) {
+      for (const key in source) {
+        if (!utils.hasOwnProp.call(source, key)) {
+          continue;
+        }
+
+        const prevSource = sourceOf.get(key);
+
+        if (prevSource == null) {
+          sourceOf.set(key, source);
+        } else if (
+          utils.resolveOrigin(prevSource, key) !==
+          utils.resolveOrigin(source, key)
+        ) {
+          ambiguousKeys.add(key);
+        }
+      }
+    }
+
+    for (const [key, source] of sourceOf) {
+      if (!ambiguousKeys.has(key)) {
+        utils.defineGetter(nsExports, source, key);
+      }
+    }
 
     return nsExports;
   }
 
-  function createContext(): ModuleContext {
+  function createMeta(id: ModuleId): ModuleMeta {
+    return {
+      id,
+      get url() {
+        return urlRegistry.get(id) ?? id;
+      },
+      resolve: (specifier) => urlRegistry.get(specifier) ?? specifier,
+    };
+  }
+
+  function createContext(id: ModuleId): ModuleContext {
     const module = { exports: createExports() };
 
     return {
+      // `import.meta`
+      meta: createMeta(id),
       // Exports object
       module,
       // Exports function
       //
       // `context.exports(...);`
+      // `context.exports(..., ...sources);`
       // `context.exports.ns(...);`
       exports: Object.assign(
-        ((definitions) => {
-          __exports(module.exports, definitions);
+        ((definitions, ...sources) => {
+          __exports(module.exports, definitions, sources);
         }) as ModuleExports,
         { ns: toNamespaceExports },
       ),
@@ -70,7 +135,7 @@ export function createGlobalModule(): GlobalModule {
     const module = moduleRegistry.get(id) ?? ({} as Module);
 
     module.id = id;
-    module.context = createContext();
+    module.context = createContext(id);
     moduleRegistry.set(id, module);
 
     return module.context;
@@ -78,6 +143,7 @@ export function createGlobalModule(): GlobalModule {
 
   function clear(): void {
     moduleRegistry.clear();
+    urlRegistry.clear();
   }
 
   function getRegistry(): Map<ModuleId, Module> {
@@ -88,6 +154,8 @@ export function createGlobalModule(): GlobalModule {
     register,
     require,
     import: (id) => Promise.resolve(require(id)),
+    registerUrl,
+    url,
     getRegistry,
     getModule,
     clear,
@@ -58,6 +58,8 @@ describe('@global-modules/runtime', () => {
       expect(typeof globalRegistry.getRegistry).toEqual('function');
       expect(typeof globalRegistry.getModule).toEqual('function');
       expect(typeof globalRegistry.clear).toEqual('function');
+      expect(typeof globalRegistry.registerUrl).toEqual('function');
+      expect(typeof globalRegistry.url).toEqual('function');
     });
   });
 
@@ -100,6 +102,49 @@ describe('@global-modules/runtime', () => {
     });
   });
 
+  describe('url', () => {
+    let context: SandboxContext;
+
+    beforeAll(() => {
+      context = createSandboxContext({ URL });
+      context.setup();
+    });
+
+    it('should return the registered URL', () => {
+      context.evaluate(
+        `__modules.registerUrl('logo', 'https://example.com/assets/logo.png');`,
+      );
+
+      expect(String(context.evaluate(`__modules.url('logo');`))).toEqual(
+        'https://example.com/assets/logo.png',
+      );
+    });
+
+    it('should provide the module meta', () => {
+      context.evaluate(
+        `__modules.registerUrl('mod', 'https://example.com/mod.js');`,
+      );
+
+      expect(
+        context.evaluate(`
+          var meta = __modules.register('mod').meta;
+          [meta.id, meta.url, meta.resolve('logo'), meta.resolve('unknown')];
+        `),
+      ).toEqual([
+        'mod',
+        'https://example.com/mod.js',
+        'https://example.com/assets/logo.png',
+        'unknown',
+      ]);
+    });
+
+    it('should throw an error for a non-registered URL', () => {
+      expect(() =>
+        context.evaluate(`__modules.url('non-registered');`),
+      ).toThrowError();
+    });
+  });
+
   describe('getRegistry', () => {
     let context: SandboxContext;
 
@@ -184,6 +229,122 @@ describe('@global-modules/runtime', () => {
       });
     });
 
+    it('Re-export (all) from multiple modules', () => {
+      context.evaluate(`
+        var __ctx = __modules.register('1');
+        __ctx.exports(function () {
+          return { foo: 1, bar: 2, default: 3 };
+        });
+      `);
+
+      context.evaluate(`
+        var __ctx = __modules.register('2');
+        __ctx.exports(function () {
+          return { bar: 4, baz: 5, qux: 6, default: 7 };
+        });
+      `);
+
+      context.evaluate(`
+        var __ctx = __modules.register('3');
+        var mod1 = global.__modules.require('1');
+        var mod2 = global.__modules.require('2');
+        __ctx.exports(function () {
+          return { qux: 'explicit' };
+        });
+        __ctx.exports(function () {
+          return __ctx.exports.ns(mod1, mod2);
+        });
+      `);
+
+      context.evaluate(`
+        var mod = global.__modules.require('3');
+        print(mod);
+      `);
+
+      expect(mockedPrint).toBeCalledWith({
+        qux: 'explicit',
+        foo: 1,
+        baz: 5,
+        // The ambiguous `bar` field and the `default` field should be excluded.
+      });
+    });
+
+    it('Re-export (all) with a single exports call', () => {
+      context.evaluate(`
+        var __ctx = __modules.register('1');
+        __ctx.exports(function () {
+          return { foo: 1, bar: 2, default: 3 };
+        });
+      `);
+
+      context.evaluate(`
+        var __ctx = __modules.register('2');
+        __ctx.exports(function () {
+          return { bar: 4, baz: 5, qux: 6, default: 7 };
+        });
+      `);
+
+      context.evaluate(`
+        var __ctx = __modules.register('3');
+        var mod1 = global.__modules.require('1');
+        var mod2 = global.__modules.require('2');
+        __ctx.exports(function () {
+          return { qux: 'explicit' };
+        }, mod1, mod2);
+      `);
+
+      context.evaluate(`
+        var mod = global.__modules.require('3');
+        print(mod);
+      `);
+
+      expect(mockedPrint).toBeCalledWith({
+        qux: 'explicit',
+        foo: 1,
+        baz: 5,
+      });
+    });
+
+    it('Re-export (all) the same origin through different paths', () => {
+      context.evaluate(`
+        var __ctx = __modules.register('1');
+        __ctx.exports(function () {
+          return { foo: 1 };
+        });
+      `);
+
+      context.evaluate(`
+        var __ctx = __modules.register('2');
+        var mod = global.__modules.require('1');
+        __ctx.exports(function () {
+          return { bar: 2 };
+        });
+        __ctx.exports(function () {
+          return __ctx.exports.ns(mod);
+        });
+      `);
+
+      context.evaluate(`
+        var __ctx = __modules.register('3');
+        var mod1 = global.__modules.require('1');
+        var mod2 = global.__modules.require('2');
+        __ctx.exports(function () {
+          return __ctx.exports.ns(mod1, mod2);
+        });
+      `);
+
+      context.evaluate(`
+        var mod = global.__modules.require('3');
+        print(mod);
+      `);
+
+      expect(mockedPrint).toBeCalledWith({
+        // Not ambiguous (both of them refer to the module '1')
+        foo: 1,
+        bar: 2,
+      });
+    });
+
     it('Update the existing module', () => {
       context.evaluate(`
         var __ctx = __modules.register('1');
@@ -13,11 +13,35 @@ export interface ModuleContext {
   module: {
     exports: Exports;
   };
+  meta: ModuleMeta;
+}
+
+/**
+ * Replacement of `import.meta` in the runtime phase.
+ *
+ * Hosts may define additional properties (eg. `env`).
+ */
+export interface ModuleMeta {
+  /**
+   * The module id.
+   */
+  id: ModuleId;
+  /**
+   * The registered URL of the module (or the module id if not registered).
+   */
+  readonly url: string;
+  /**
+   * Returns the registered URL of the specifier (or the specifier itself if not registered).
+   *
+   * Static specifiers are mapped by `paths` at transform time.
+   */
+  resolve: (specifier: string) => string;
+  [key: string]: unknown;
 }
 
 export interface ModuleExports {
-  (definitions: () => Exports): void;
-  ns: (exports: Exports) => Exports;
+  (definitions: () => Exports, ...sources: Exports[]): void;
+  ns: (...exports: Exports[]) => Exports;
 }
 export type ModuleRequire = (id: ModuleId) => Exports;
 export type ModuleImport = (id: ModuleId) => Promise<Exports>;
@@ -37,6 +61,14 @@ export interface GlobalModule {
    * Get module exports from global registry (promise).
    */
   import: ModuleImport;
+  /**
+   * Register the URL of the worker or asset (referenced by `new URL(src, import.meta.url)`).
+   */
+  registerUrl: (id: ModuleId, url: string | URL) => void;
+  /**
+   * Get the registered URL of the worker or asset.
+   */
+  url: (id: ModuleId) => URL;
   /**
    * Get module from global registry.
    */
@@ -46,7 +78,7 @@ export interface GlobalModule {
    */
   getRegistry: () => Map<ModuleId, Module>;
   /**
-   * Clear all modules from the registry.
+   * Clear all modules and URLs from the registry.
    */
   clear: () => void;
 }
@@ -2,6 +2,38 @@ import type { ModuleRequire } from './types';
 
 const hasOwnProp = Object.prototype.hasOwnProperty;
 const defProp = Object.defineProperty;
+
+/**
+ * Origin objects of the copied properties (`destination -> key -> origin`).
+ *
+ * Used to determine whether the same name re-exported through different paths
+ * refers to the same binding.
+ */
+const origins = new WeakMap<object, Record<string, object>>();
+
+const resolveOrigin = (source: object, key: string): object =>
+  origins.get(source)?.[key] ?? source;
+
+const defineGetter = <T extends object>(
+  destination: T,
+  source: T,
+  key: Extract<keyof T, string>,
+): void => {
+  let destinationOrigins = origins.get(destination);
+
+  if (destinationOrigins == null) {
+    destinationOrigins = Object.create(null) as Record<string, object>;
+    origins.set(destination, destinationOrigins);
+  }
+
+  destinationOrigins[key] = resolveOrigin(source, key);
+
+  defProp(destination, key, {
+    enumerable: true,
+    get: () => source[key],
+  });
+};
+
 const copyProps = <T extends object>(
   destination: T,
   source: T,
@@ -13,10 +45,7 @@ const copyProps = <T extends object>(
       hasOwnProp.call(source, key) &&
       !hasOwnProp.call(destination, key)
     ) {
-      defProp(destination, key, {
-        enumerable: true,
-        get: () => source[key],
-      });
+      defineGetter(destination, source, key);
     }
   }
 
@@ -26,4 +55,11 @@ const copyProps = <T extends object>(
 const toImport = (require: ModuleRequire) => (source: string) =>
   Promise.resolve(require(source));
 
-export { hasOwnProp, defProp, copyProps, toImport };
+export {
+  hasOwnProp,
+  defProp,
+  resolveOrigin,
+  defineGetter,
+  copyProps,
+  toImport,
+};
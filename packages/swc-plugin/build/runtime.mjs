@@ -0,0 +1,16 @@
+import fs from 'node:fs/promises';
+import path from 'node:path';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const runtimePath = require.resolve('@global-modules/runtime');
+const runtimeCode = await fs.readFile(runtimePath, 'utf-8');
+
+/**
+ * Embedded registry of the native bundles (`include_str!("./runtime.js")`).
+ */
+await fs.writeFile(
+  path.resolve(import.meta.dirname, '../transform/src/runtime.js'),
+  `// Generated from \`@global-modules/runtime\` by \`build/runtime.mjs\`. Do not edit.\n${runtimeCode}`,
+  'utf-8',
+);
@@ -4,7 +4,7 @@ use swc_core::{
     ecma::ast::Program,
     plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
 };
-use swc_global_modules::global_modules;
+use swc_global_modules::{global_modules, Options};
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,6 +12,8 @@ struct GlobalModuleConfig {
     id: String,
     runtime: bool,
     paths: Option<AHashMap<String, String>>,
+    #[serde(flatten)]
+    options: Options,
 }
 
 #[plugin_transform]
@@ -30,6 +32,7 @@ pub fn global_modules_plugin(
         config.id,
         config.runtime,
         config.paths,
+        config.options,
         SyntaxContext::empty().apply_mark(metadata.unresolved_mark),
     ))
 }
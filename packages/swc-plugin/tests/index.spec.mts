@@ -3,10 +3,26 @@ import { bundle, bundleWithFoo } from './utils/bundle.js';
 import { evaluateOnSandbox } from './utils/sandbox.js';
 import { tests } from './module-tests.mjs';
 
+/**
+ * Asserts the module registration of the transformed `foo` module (ID: `0`).
+ */
+function expectRegistered(
+  code: string,
+  { runtime }: { runtime: boolean },
+): void {
+  expect(code).toMatch(/global\.__modules\.register\(["']0["']\)/);
+  // Exports are registered with lazy getters, without the binding variables.
+  expect(code).not.toMatch(/\b__x\d*\b/);
+
+  if (runtime) {
+    expect(code).not.toMatch(/^\s*(import|export)\b/m);
+  }
+}
+
 describe('@global-modules/swc-plugin', () => {
   describe('Bundle phase', () => {
     it('[ESM] Basics', async () => {
-      const bundleCode = await bundleWithFoo(
+      const { bundleCode } = await bundleWithFoo(
         {
           entry: `
           import * as mod from './foo';
@@ -39,7 +55,7 @@ describe('@global-modules/swc-plugin', () => {
     });
 
     it('[ESM] Export with declaration statements', async () => {
-      const bundleCode = await bundleWithFoo(
+      const { bundleCode } = await bundleWithFoo(
         {
           entry: `
           import * as mod from './foo';
@@ -64,7 +80,7 @@ describe('@global-modules/swc-plugin', () => {
     });
 
     it('[CJS] Basics', async () => {
-      const bundleCode = await bundleWithFoo(
+      const { bundleCode } = await bundleWithFoo(
         {
           entry: `
           const mod = require('./foo');
@@ -114,7 +130,7 @@ describe('@global-modules/swc-plugin', () => {
 
   describe('Runtime phase', () => {
     it('[ESM] Basics', async () => {
-      const bundleCode = await bundleWithFoo(
+      const { bundleCode } = await bundleWithFoo(
         {
           entry: `
           import * as mod from './foo';
@@ -147,7 +163,7 @@ describe('@global-modules/swc-plugin', () => {
     });
 
     it('[ESM] Export with declaration statements', async () => {
-      const bundleCode = await bundleWithFoo(
+      const { bundleCode } = await bundleWithFoo(
         {
           entry: `
           import * as mod from './foo';
@@ -171,8 +187,187 @@ describe('@global-modules/swc-plugin', () => {
       expect(bridge).toBeCalledWith({ newObj: { value: 0, key: 'key' } });
     });
 
+    it('[ESM] Named re-exports', async () => {
+      const { bundleCode, fooCode } = await bundleWithFoo(
+        {
+          entry: `
+          global.__modules.register('./re-exp').exports(function () {
+            return { rx0: 0, rx1: 1, rx2: 2, default: 'default' };
+          });
+
+          require('./foo');
+
+          bridge(global.__modules.require('0'));
+          `,
+          foo: `
+          export { rx0, rx1, rx2 } from './re-exp';
+          export { rx0 as renamed, default as rx3 } from './re-exp';
+          `,
+        },
+        { runtime: true },
+      );
+
+      const bridge = vi.fn();
+      evaluateOnSandbox(bundleCode, { bridge });
+
+      expectRegistered(fooCode, { runtime: true });
+      expect(fooCode).toMatch(
+        /get\s*["']?renamed["']?\s*\(\)\s*\{\s*return __mod\d*\.rx0;/,
+      );
+      expect(fooCode).toMatch(
+        /get\s*["']?rx3["']?\s*\(\)\s*\{\s*return __mod\d*\.default;/,
+      );
+      expect(fooCode).toMatch(
+        /global\.__modules\.require\(["']\.\/re-exp["']\)/,
+      );
+      expect(bridge).toBeCalledWith({
+        rx0: 0,
+        rx1: 1,
+        rx2: 2,
+        renamed: 0,
+        rx3: 'default',
+      });
+    });
+
+    it('[ESM] Re-exports with explicit and star exports', async () => {
+      const { bundleCode, fooCode } = await bundleWithFoo(
+        {
+          entry: `
+          global.__modules.register('./star').exports(function () {
+            return { foo: 'star', bar: 'star', default: 'star-default' };
+          });
+          global.__modules.register('./a').exports(function () {
+            return { default: 'a-default' };
+          });
+
+          require('./foo');
+
+          bridge(global.__modules.require('0'));
+          `,
+          foo: `
+          export * from './star';
+          export * as ns from './star';
+          export { default } from './a';
+          export const bar = 'explicit';
+          `,
+        },
+        { runtime: true },
+      );
+
+      const bridge = vi.fn();
+      evaluateOnSandbox(bundleCode, { bridge });
+
+      expectRegistered(fooCode, { runtime: true });
+      // Star exports are resolved by the runtime (explicit names take precedence).
+      expect(fooCode).toMatch(/__context\.exports\.ns\(__mod\d*\)/);
+      expect(fooCode).toMatch(
+        /get\s*["']?default["']?\s*\(\)\s*\{\s*return __mod\d*\.default;/,
+      );
+      expect(fooCode).toMatch(/["']?ns["']?\s*:\s*__mod\d*/);
+      expect(bridge).toBeCalledWith({
+        foo: 'star',
+        bar: 'explicit',
+        default: 'a-default',
+        ns: { foo: 'star', bar: 'star', default: 'star-default' },
+      });
+    });
+
+    it('[ESM] Ambiguous star exports', async () => {
+      const { bundleCode, fooCode } = await bundleWithFoo(
+        {
+          entry: `
+          global.__modules.register('./star-1').exports(function () {
+            return { foo: 1, dup: 'star-1', default: 'star-1-default' };
+          });
+          global.__modules.register('./star-2').exports(function () {
+            return { bar: 2, dup: 'star-2' };
+          });
+
+          require('./foo');
+
+          bridge(global.__modules.require('0'));
+          `,
+          foo: `
+          export * from './star-1';
+          export * from './star-2';
+          `,
+        },
+        { runtime: true },
+      );
+
+      const bridge = vi.fn();
+      evaluateOnSandbox(bundleCode, { bridge });
+
+      expectRegistered(fooCode, { runtime: true });
+      expect(fooCode).toMatch(
+        /__context\.exports\.ns\(__mod\d*, __mod\d*\)/,
+      );
+      // `dup` is exported by both modules (ambiguous) and `default` is never re-exported.
+      expect(bridge).toBeCalledWith({ foo: 1, bar: 2 });
+    });
+
+    it('[ESM] Worker and asset URLs', async () => {
+      const { bundleCode, fooCode } = await bundleWithFoo(
+        {
+          entry: `
+          global.__modules.registerUrl('./logo.png', 'https://example.com/assets/logo.png');
+
+          require('./foo');
+
+          bridge(String(global.__modules.require('0').logo));
+          `,
+          foo: `
+          export const logo = new URL('./logo.png', import.meta.url);
+          `,
+        },
+        { runtime: true },
+      );
+
+      const bridge = vi.fn();
+      evaluateOnSandbox(bundleCode, { bridge, URL });
+
+      expectRegistered(fooCode, { runtime: true });
+      expect(fooCode).toMatch(
+        /global\.__modules\.url\(["']\.\/logo\.png["']\)/,
+      );
+      expect(bridge).toBeCalledWith('https://example.com/assets/logo.png');
+    });
+
+    it('[ESM] import.meta', async () => {
+      const { bundleCode, fooCode } = await bundleWithFoo(
+        {
+          entry: `
+          global.__modules.registerUrl('0', 'https://example.com/foo.js');
+          global.__modules.registerUrl('./logo.png', 'https://example.com/assets/logo.png');
+
+          require('./foo');
+
+          bridge(global.__modules.require('0'));
+          `,
+          foo: `
+          export const id = import.meta.id;
+          export const url = import.meta.url;
+          export const logo = import.meta.resolve('./logo.png');
+          `,
+        },
+        { runtime: true },
+      );
+
+      const bridge = vi.fn();
+      evaluateOnSandbox(bundleCode, { bridge });
+
+      expectRegistered(fooCode, { runtime: true });
+      expect(fooCode).toContain('__context.meta.url');
+      expect(fooCode).not.toContain('import.meta');
+      expect(bridge).toBeCalledWith({
+        id: '0',
+        url: 'https://example.com/foo.js',
+        logo: 'https://example.com/assets/logo.png',
+      });
+    });
+
     it('[CJS] Basics', async () => {
-      const bundleCode = await bundleWithFoo(
+      const { bundleCode } = await bundleWithFoo(
         {
           entry: `
           const mod = require('./foo');
@@ -70,8 +70,9 @@ export async function bundleWithFoo(
 
   const bundleCode = buildResult.outputFiles[0]?.text;
   assert(bundleCode, 'invalid bundle result');
+  assert(fooCode, 'invalid transform result');
 
-  return bundleCode;
+  return { bundleCode, fooCode };
 }
 
 export async function bundle(
@@ -0,0 +1,108 @@
+//! Emits an HMR update chunk of the changed modules.
+//!
+//! ```sh
+//! global-modules-update [--paths <paths.json>] [--options <options.json>] [--out <file>] [<id>=]<file>...
+//! ```
+//!
+//! Each changed file is transformed in runtime phase with the shared `paths` map,
+//! and the chunk is written to the standard output (or `--out`).
+//! The module ID defaults to the file path.
+
+use std::{env, fs, process};
+
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{emit_update, ModuleSource, Options};
+
+const USAGE: &str = "usage: global-modules-update [--paths <paths.json>] [--options <options.json>] [--out <file>] [<id>=]<file>...";
+
+/// Command line arguments.
+#[derive(Default)]
+struct Args {
+    /// Path of the `paths` map (JSON)
+    paths: Option<String>,
+    /// Path of the transform options (JSON)
+    options: Option<String>,
+    /// Output path
+    out: Option<String>,
+    /// Changed modules (`(id, file)`)
+    modules: Vec<(String, String)>,
+}
+
+fn main() {
+    if let Err(message) = run() {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args(env::args().skip(1))?;
+    let paths = args
+        .paths
+        .map(|path| read_json::<AHashMap<String, String>>(&path))
+        .transpose()?;
+    let options = args
+        .options
+        .map(|path| read_json::<Options>(&path))
+        .transpose()?
+        .unwrap_or_default();
+    let sources = args
+        .modules
+        .iter()
+        .map(|(id, file)| {
+            read_file(file).map(|code| ModuleSource::new(id.as_str(), file.as_str(), code))
+        })
+        .collect::<Result<Vec<ModuleSource>, String>>()?;
+
+    let chunk = emit_update(&sources, &paths, &options)
+        .map_err(|error| format!("{}: {}", error.filename, error.messages.join("\n")))?;
+
+    match args.out {
+        Some(out) => fs::write(&out, chunk.code).map_err(|error| format!("{}: {}", out, error)),
+        None => {
+            print!("{}", chunk.code);
+            Ok(())
+        }
+    }
+}
+
+/// Parses the command line arguments.
+fn parse_args(mut raw_args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args::default();
+
+    while let Some(arg) = raw_args.next() {
+        let mut value = |name: &str| {
+            raw_args
+                .next()
+                .ok_or_else(|| format!("missing value of `{}`\n{}", name, USAGE))
+        };
+
+        match arg.as_str() {
+            "--paths" => args.paths = Some(value("--paths")?),
+            "--options" => args.options = Some(value("--options")?),
+            "--out" => args.out = Some(value("--out")?),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if arg.starts_with("--") => {
+                return Err(format!("unknown option `{}`\n{}", arg, USAGE));
+            }
+            _ => args.modules.push(match arg.split_once('=') {
+                Some((id, file)) => (id.to_string(), file.to_string()),
+                None => (arg.clone(), arg),
+            }),
+        }
+    }
+
+    if args.modules.is_empty() {
+        return Err(format!("no changed files\n{}", USAGE));
+    }
+
+    Ok(args)
+}
+
+fn read_file(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &str) -> Result<T, String> {
+    serde_json::from_str(&read_file(path)?).map_err(|error| format!("{}: {}", path, error))
+}
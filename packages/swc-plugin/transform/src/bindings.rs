@@ -0,0 +1,99 @@
+use swc_core::{
+    common::collections::AHashSet,
+    ecma::{
+        ast::*,
+        utils::find_pat_ids,
+        visit::{noop_visit_type, Visit, VisitWith},
+    },
+};
+
+/// Top-level local bindings of the module.
+///
+/// Used to decide how the exports reference the local bindings.
+///
+/// ```js
+/// export function foo() {} // Hoisted: can be referenced before the module body executes
+/// export let bar = 0;      // Not hoisted: must be referenced lazily (getter)
+///
+/// bar++;                   // Reassigned: must be referenced lazily (getter)
+/// ```
+#[derive(Debug, Default)]
+pub struct LocalBindings {
+    /// Top-level function declarations
+    fn_decls: AHashSet<Id>,
+    /// Reassigned identifiers
+    reassigned: AHashSet<Id>,
+}
+
+impl LocalBindings {
+    /// Collects the local bindings of the module items
+    pub fn collect(items: &[ModuleItem]) -> Self {
+        let mut collector = LocalBindingsCollector::default();
+
+        for item in items {
+            if let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+                ident,
+                declare: false,
+                ..
+            }))) = item
+            {
+                collector.bindings.fn_decls.insert(ident.to_id());
+            }
+
+            item.visit_with(&mut collector);
+        }
+
+        collector.bindings
+    }
+
+    /// Returns `true` if the identifier is a hoisted function declaration
+    /// that is never reassigned (its value is available before the module body executes).
+    pub fn is_hoisted(&self, ident: &Ident) -> bool {
+        let id = ident.to_id();
+
+        self.fn_decls.contains(&id) && !self.reassigned.contains(&id)
+    }
+}
+
+#[derive(Default)]
+struct LocalBindingsCollector {
+    bindings: LocalBindings,
+}
+
+impl Visit for LocalBindingsCollector {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+        match &assign_expr.left {
+            AssignTarget::Simple(SimpleAssignTarget::Ident(binding_ident)) => {
+                self.bindings.reassigned.insert(binding_ident.to_id());
+            }
+            AssignTarget::Pat(pat) => {
+                self.bindings
+                    .reassigned
+                    .extend(find_pat_ids::<_, Id>(&Pat::from(pat.clone())));
+            }
+            _ => {}
+        }
+
+        assign_expr.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update_expr: &UpdateExpr) {
+        if let Expr::Ident(ident) = &*update_expr.arg {
+            self.bindings.reassigned.insert(ident.to_id());
+        }
+
+        update_expr.visit_children_with(self);
+    }
+
+    fn visit_for_head(&mut self, for_head: &ForHead) {
+        if let ForHead::Pat(pat) = for_head {
+            self.bindings
+                .reassigned
+                .extend(find_pat_ids::<_, Id>(&**pat));
+        }
+
+        for_head.visit_children_with(self);
+    }
+}
@@ -0,0 +1,41 @@
+// Lazy module factories on top of the global module registry (`@global-modules/runtime`).
+//
+// Returns a `define(id, factory)` function that registers lazy module factories.
+// Factories are executed on the first `require(id)` / `import(id)` call.
+function (global) {
+  var factories = Object.create(null);
+  var modules = global.__modules;
+
+  function load(id) {
+    var factory = factories[id];
+
+    if (factory) {
+      // Remove before execution to handle circular dependencies.
+      delete factories[id];
+
+      var module = { exports: {} };
+      factory.call(module.exports, module, module.exports);
+    }
+  }
+
+  var baseRequire = modules.require;
+  var baseImport = modules.import;
+
+  modules.require = function (id) {
+    load(id);
+    return baseRequire(id);
+  };
+
+  modules.import = function (id) {
+    try {
+      load(id);
+    } catch (error) {
+      return Promise.reject(error);
+    }
+    return baseImport(id);
+  };
+
+  return function define(id, factory) {
+    factories[id] = factory;
+  };
+}
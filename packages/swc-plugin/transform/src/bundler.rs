@@ -0,0 +1,122 @@
+use swc_core::common::collections::AHashMap;
+
+use crate::{
+    compiler::{check_wrapped_outputs, transform_sorted, ModuleSource, TransformError},
+    graph::ModuleId,
+    options::Options,
+};
+
+/// Embedded global module registry (generated from `@global-modules/runtime`).
+///
+/// Evaluated only if the registry is not defined yet (eg. the runtime is not loaded by the host).
+const RUNTIME: &str = include_str!("./runtime.js");
+
+/// Inline bootstrap of the lazy module factories.
+///
+/// Wraps `require` and `import` of the registry
+/// and returns a `define(id, factory)` function for lazy module factories.
+const BOOTSTRAP: &str = include_str!("./bootstrap.js");
+
+/// Global module compatible bundle.
+#[derive(Debug, Clone)]
+pub struct Bundle {
+    /// Bundled module IDs (dependencies come first)
+    pub ids: Vec<ModuleId>,
+    /// Bundle code
+    pub code: String,
+    /// External dependencies of the bundled modules (provided by the host)
+    pub externals: Vec<String>,
+}
+
+/// Bundles the modules into a single script.
+///
+/// Each module is transformed in runtime phase and wrapped with a factory keyed by its ID.
+/// Factories are executed lazily on the first `global.__modules.require(id)` call,
+/// and the entry modules are required at the end of the script.
+///
+/// The bundle is self-contained. The registry of `@global-modules/runtime` is embedded
+/// and defined on the global object unless it is already loaded by the host.
+///
+/// Factories are plain functions, so modules containing top-level `await` can't be bundled.
+///
+/// External dependencies are not bundled. Because the factories can't contain
+/// import statements, they must be provided by the host accessor (`externals.accessor`),
+/// and an error is returned if an external dependency is found without the accessor.
+///
+/// ```js
+/// (function (global) {
+///   if (global.__modules == null) {
+///     /* runtime */
+///   }
+///   var __define = (function (global) { /* bootstrap */ })(global);
+///   // 1001
+///   __define("1001", function (module, exports) {
+///     const __context = global.__modules.register("1001");
+///     // ...
+///   });
+///   // 1000
+///   __define("1000", function (module, exports) {
+///     const __context = global.__modules.register("1000");
+///     const { foo } = global.__modules.require("1001");
+///     // ...
+///   });
+///   global.__modules.require("1000");
+/// })(typeof globalThis !== 'undefined' ? globalThis : this);
+/// ```
+pub fn bundle(
+    sources: &[ModuleSource],
+    entries: &[ModuleId],
+    paths: &Option<AHashMap<String, String>>,
+    options: &Options,
+) -> Result<Bundle, TransformError> {
+    let outputs = transform_sorted(sources, paths, options)?;
+
+    check_wrapped_outputs(sources, &outputs, options, "bundled modules")?;
+
+    let ids = outputs
+        .iter()
+        .map(|output| output.id.clone())
+        .collect::<Vec<ModuleId>>();
+    let mut externals = Vec::new();
+
+    for external in outputs.iter().flat_map(|output| &output.externals) {
+        if !externals.contains(external) {
+            externals.push(external.clone());
+        }
+    }
+
+    let mut code = format!(
+        "(function (global) {{\nif (global.__modules == null) {{\n{}}}\nvar __define = ({})(global);\n",
+        RUNTIME,
+        BOOTSTRAP.trim_end()
+    );
+
+    for output in &outputs {
+        code.push_str(&format!(
+            "// {}\n__define({}, function (module, exports) {{\n{}}});\n",
+            output.id.replace('\n', " "),
+            to_js_string(&output.id),
+            output.code
+        ));
+    }
+
+    for entry in entries {
+        code.push_str(&format!(
+            "global.__modules.require({});\n",
+            to_js_string(entry)
+        ));
+    }
+
+    code.push_str("})(typeof globalThis !== 'undefined' ? globalThis : this);\n");
+
+    Ok(Bundle {
+        ids,
+        code,
+        externals,
+    })
+}
+
+/// Returns a JavaScript string literal of the given value.
+fn to_js_string(value: &str) -> String {
+    serde_json::to_string(value).expect("failed to serialize string")
+}
@@ -0,0 +1,296 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use swc_core::{
+    common::{
+        collections::AHashMap,
+        comments::SingleThreadedComments,
+        errors::{DiagnosticBuilder, Emitter, Handler, HANDLER},
+        sync::Lrc,
+        FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, Program},
+        codegen::{text_writer::JsWriter, Config, Emitter as CodeEmitter},
+        parser::{parse_file_as_program, EsSyntax, Syntax},
+        transforms::base::{fixer::fixer, hygiene::hygiene, resolver},
+        utils::contains_top_level_await,
+        visit::VisitMutWith,
+    },
+};
+
+use crate::{
+    graph::{ExportUsage, ModuleGraph},
+    models::UrlDep,
+    module_kind::ModuleKind,
+    options::Options,
+    transformer::GlobalModuleTransformer,
+    ModuleId,
+};
+
+/// Source of a module to transform.
+#[derive(Debug, Clone)]
+pub struct ModuleSource {
+    /// Module ID
+    pub id: ModuleId,
+    /// File name (used for error reporting and syntax detection)
+    pub filename: String,
+    /// Source code
+    pub code: String,
+}
+
+impl ModuleSource {
+    /// Creates a new module source
+    pub fn new(
+        id: impl Into<ModuleId>,
+        filename: impl Into<String>,
+        code: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            filename: filename.into(),
+            code: code.into(),
+        }
+    }
+}
+
+/// Transformed module.
+#[derive(Debug, Clone)]
+pub struct TransformOutput {
+    /// Module ID
+    pub id: ModuleId,
+    /// Transformed code
+    pub code: String,
+    /// Dependencies (mapped by `paths`)
+    pub deps: Vec<String>,
+    /// URL dependencies (workers and assets, mapped by `paths`)
+    pub url_deps: Vec<UrlDep>,
+    /// External dependencies (not mapped by `paths`)
+    pub externals: Vec<String>,
+    /// Usage of the dependencies' exports (see `ModuleGraph::used_exports`)
+    pub export_usage: ExportUsage,
+    /// Module kind
+    pub kind: ModuleKind,
+    /// Whether the module contains top-level `await`
+    pub top_level_await: bool,
+    /// Warning messages
+    pub warnings: Vec<String>,
+}
+
+/// Error while transforming a module.
+#[derive(Debug, Clone)]
+pub struct TransformError {
+    /// File name
+    pub filename: String,
+    /// Error messages
+    pub messages: Vec<String>,
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to transform '{}': {}",
+            self.filename,
+            self.messages.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+/// Transforms the module source with the global module transformer.
+///
+/// Input code must be plain JavaScript (TypeScript and JSX are expected to be stripped).
+pub fn transform(
+    source: &ModuleSource,
+    runtime: bool,
+    paths: &Option<AHashMap<String, String>>,
+    options: &Options,
+) -> Result<TransformOutput, TransformError> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let comments = SingleThreadedComments::default();
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let handler = Handler::with_emitter(
+        true,
+        false,
+        Box::new(CollectingEmitter {
+            messages: messages.clone(),
+        }),
+    );
+    let to_error = |messages: Vec<String>| TransformError {
+        filename: source.filename.clone(),
+        messages,
+    };
+
+    GLOBALS.set(&Globals::new(), || {
+        HANDLER.set(&handler, || {
+            let fm = cm.new_source_file(
+                Lrc::new(FileName::Custom(source.filename.clone())),
+                source.code.clone(),
+            );
+            let mut program = parse_file_as_program(
+                &fm,
+                Syntax::Es(EsSyntax {
+                    jsx: source.filename.ends_with(".jsx"),
+                    ..Default::default()
+                }),
+                EsVersion::latest(),
+                Some(&comments),
+                &mut Vec::new(),
+            )
+            .map_err(|error| to_error(vec![error.kind().msg().to_string()]))?;
+
+            let unresolved_mark = Mark::new();
+            let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
+
+            program.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+
+            let top_level_await = contains_top_level_await(&program);
+            let mut transformer = GlobalModuleTransformer::new(
+                source.id.clone(),
+                runtime,
+                paths.clone(),
+                options.clone(),
+                unresolved_ctxt,
+            );
+
+            program.visit_mut_with(&mut transformer);
+
+            let collected_deps = transformer.take_collected_deps();
+            let kind = transformer.kind();
+
+            if handler.has_errors() {
+                return Err(to_error(messages.lock().unwrap().clone()));
+            }
+
+            program.visit_mut_with(&mut hygiene());
+            program.visit_mut_with(&mut fixer(Some(&comments)));
+
+            Ok(TransformOutput {
+                id: source.id.clone(),
+                code: print(cm.clone(), &comments, &program),
+                deps: collected_deps.deps,
+                url_deps: collected_deps.url_deps,
+                externals: collected_deps.externals,
+                export_usage: collected_deps.usage,
+                kind,
+                top_level_await,
+                warnings: messages.lock().unwrap().clone(),
+            })
+        })
+    })
+}
+
+/// Transforms the module sources in runtime phase
+/// and returns the outputs sorted by dependencies (dependencies come first).
+pub(crate) fn transform_sorted(
+    sources: &[ModuleSource],
+    paths: &Option<AHashMap<String, String>>,
+    options: &Options,
+) -> Result<Vec<TransformOutput>, TransformError> {
+    let mut graph = ModuleGraph::new();
+    let mut outputs = sources
+        .iter()
+        .map(|source| transform(source, true, paths, options))
+        .collect::<Result<Vec<TransformOutput>, TransformError>>()?;
+
+    for output in &outputs {
+        graph.add_module(output.id.clone(), output.deps.clone());
+    }
+
+    let ids = graph.sort_by_deps(
+        &outputs
+            .iter()
+            .map(|output| output.id.clone())
+            .collect::<Vec<ModuleId>>(),
+    );
+
+    Ok(ids
+        .iter()
+        .map(|id| {
+            let index = outputs
+                .iter()
+                .position(|output| &output.id == id)
+                .expect("transformed module not found");
+
+            outputs.swap_remove(index)
+        })
+        .collect())
+}
+
+/// Checks the outputs to be wrapped with plain functions (eg. bundle factories).
+///
+/// Returns an error if a module contains top-level `await`,
+/// or has external dependencies without the host accessor (`externals.accessor`)
+/// because the functions can't contain import statements.
+pub(crate) fn check_wrapped_outputs(
+    sources: &[ModuleSource],
+    outputs: &[TransformOutput],
+    options: &Options,
+    target: &str,
+) -> Result<(), TransformError> {
+    let filename_of = |id: &ModuleId| {
+        sources
+            .iter()
+            .find(|source| &source.id == id)
+            .map_or_else(|| id.clone(), |source| source.filename.clone())
+    };
+
+    if let Some(output) = outputs.iter().find(|output| output.top_level_await) {
+        return Err(TransformError {
+            filename: filename_of(&output.id),
+            messages: vec![format!("top-level await is not supported in {}", target)],
+        });
+    }
+
+    if options.externals.accessor.is_none() {
+        if let Some(output) = outputs.iter().find(|output| !output.externals.is_empty()) {
+            return Err(TransformError {
+                filename: filename_of(&output.id),
+                messages: output
+                    .externals
+                    .iter()
+                    .map(|external| {
+                        format!(
+                            "external dependency '{}' requires `externals.accessor` in {}",
+                            external, target
+                        )
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the program with the comments.
+fn print(cm: Lrc<SourceMap>, comments: &SingleThreadedComments, program: &Program) -> String {
+    let mut buf = Vec::new();
+    {
+        let mut emitter = CodeEmitter {
+            cfg: Config::default(),
+            cm: cm.clone(),
+            comments: Some(comments),
+            wr: JsWriter::new(cm, "\n", &mut buf, None),
+        };
+
+        emitter.emit_program(program).expect("failed to emit code");
+    }
+
+    String::from_utf8(buf).expect("invalid utf-8 output")
+}
+
+/// Emitter that collects the diagnostic messages.
+struct CollectingEmitter {
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl Emitter for CollectingEmitter {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        self.messages.lock().unwrap().push(db.message());
+    }
+}
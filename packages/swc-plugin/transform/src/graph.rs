@@ -0,0 +1,506 @@
+use swc_core::{
+    common::{
+        collections::{AHashMap, AHashSet},
+        SyntaxContext,
+    },
+    ecma::{ast::*, visit::VisitMutWith},
+};
+
+use crate::{
+    idents::Idents,
+    models::{Dep, Exp, UrlDep},
+    module_collector::{create_collector, ModuleCollector},
+    module_kind::detect_resolved_module_kind,
+    options::Externals,
+    scope::ensure_resolved,
+};
+
+/// Module ID
+pub type ModuleId = String;
+
+/// A module of the graph.
+#[derive(Debug, Clone)]
+pub struct ModuleNode {
+    /// Module ID
+    pub id: ModuleId,
+    /// Dependency module IDs
+    pub deps: Vec<ModuleId>,
+    /// Whether the module accepts hot updates by itself (HMR boundary)
+    pub accept: bool,
+}
+
+/// Module dependency graph.
+///
+/// Each module is identified by the same ID that is passed to `global.__modules.register(id)`
+/// and its dependencies are the (mapped by `paths`) sources collected from the module.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    /// Modules
+    modules: AHashMap<ModuleId, ModuleNode>,
+    /// Module IDs in insertion order
+    order: Vec<ModuleId>,
+    /// Export usages of the modules
+    usages: AHashMap<ModuleId, ExportUsage>,
+}
+
+/// Exports of a module that are used by its importers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsedExports {
+    /// All exports (namespace imports, `require` and `import()` calls)
+    All,
+    /// Exports of the names
+    Names(Vec<String>),
+}
+
+impl UsedExports {
+    /// Merges the used exports and returns `true` if any export is newly used.
+    fn merge(&mut self, other: &UsedExports) -> bool {
+        match (&mut *self, other) {
+            (UsedExports::All, _) => false,
+            (_, UsedExports::All) => {
+                *self = UsedExports::All;
+                true
+            }
+            (UsedExports::Names(names), UsedExports::Names(other_names)) => {
+                let len = names.len();
+
+                for name in other_names {
+                    if !names.contains(name) {
+                        names.push(name.clone());
+                    }
+                }
+
+                names.len() != len
+            }
+        }
+    }
+}
+
+/// Re-export of a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReExport {
+    /// Exported name
+    pub name: String,
+    /// Source (mapped by `paths`)
+    pub src: ModuleId,
+    /// Name of the source module's export (`None` for `export * as name from '...'`)
+    pub orig: Option<String>,
+}
+
+/// Usage of the dependencies' exports of a module.
+///
+/// ```js
+/// import foo, { bar } from './foo'; // './foo': ["default", "bar"]
+/// import * as baz from './baz'; // './baz': All
+/// export { qux } from './qux'; // ReExport { name: "qux", src: "./qux", orig: "qux" }
+/// export * from './quux'; // star re-export './quux'
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportUsage {
+    /// Used exports of the dependencies (mapped by `paths`)
+    pub imports: Vec<(ModuleId, UsedExports)>,
+    /// Named re-exports
+    pub re_exports: Vec<ReExport>,
+    /// Sources of the re-export all statements (mapped by `paths`)
+    pub star_re_exports: Vec<ModuleId>,
+}
+
+impl ModuleGraph {
+    /// Creates a new empty module graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a module to the graph.
+    ///
+    /// If the module is already added, its dependencies are replaced
+    /// and the `accept` flag is kept.
+    pub fn add_module(&mut self, id: ModuleId, deps: Vec<ModuleId>) {
+        match self.modules.get_mut(&id) {
+            Some(module) => module.deps = dedup(deps),
+            None => {
+                self.order.push(id.clone());
+                self.modules.insert(
+                    id.clone(),
+                    ModuleNode {
+                        id,
+                        deps: dedup(deps),
+                        accept: false,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Removes a module from the graph.
+    pub fn remove_module(&mut self, id: &str) -> Option<ModuleNode> {
+        self.order.retain(|module_id| module_id != id);
+        self.usages.remove(id);
+        self.modules.remove(id)
+    }
+
+    /// Sets the export usage of the module (see `collect_export_usage`).
+    ///
+    /// Modules without the export usage are considered to use all exports of their dependencies.
+    pub fn set_export_usage(&mut self, id: &str, usage: ExportUsage) {
+        if self.modules.contains_key(id) {
+            self.usages.insert(id.to_string(), usage);
+        }
+    }
+
+    /// Marks whether the module accepts hot updates by itself.
+    pub fn set_accept(&mut self, id: &str, accept: bool) {
+        if let Some(module) = self.modules.get_mut(id) {
+            module.accept = accept;
+        }
+    }
+
+    /// Returns the module of the given ID.
+    pub fn get_module(&self, id: &str) -> Option<&ModuleNode> {
+        self.modules.get(id)
+    }
+
+    /// Checks whether the module exists in the graph.
+    pub fn has_module(&self, id: &str) -> bool {
+        self.modules.contains_key(id)
+    }
+
+    /// Returns all modules in insertion order.
+    pub fn modules(&self) -> impl Iterator<Item = &ModuleNode> {
+        self.order.iter().filter_map(|id| self.modules.get(id))
+    }
+
+    /// Returns the IDs of modules that depend on the given module.
+    pub fn dependents_of(&self, id: &str) -> Vec<&ModuleId> {
+        self.modules()
+            .filter(|module| module.deps.iter().any(|dep| dep == id))
+            .map(|module| &module.id)
+            .collect()
+    }
+
+    /// Sorts the given module IDs so that dependencies come before their dependents.
+    ///
+    /// Only the given IDs are included in the result. Cycles are broken by the given order.
+    pub fn sort_by_deps(&self, ids: &[ModuleId]) -> Vec<ModuleId> {
+        let mut pending = dedup(ids.to_vec());
+        let mut sorted = Vec::with_capacity(pending.len());
+
+        while !pending.is_empty() {
+            // The first module whose dependencies are all sorted,
+            // or the first pending module if every module is in a cycle.
+            let index = pending
+                .iter()
+                .position(|id| {
+                    self.modules.get(id).map_or(true, |module| {
+                        module
+                            .deps
+                            .iter()
+                            .all(|dep| dep == id || !pending.contains(dep))
+                    })
+                })
+                .unwrap_or(0);
+
+            sorted.push(pending.remove(index));
+        }
+
+        sorted
+    }
+
+    /// Computes the exports used at runtime for each module.
+    ///
+    /// All exports of the entry modules are used, and the used exports of the re-exporting modules
+    /// are propagated to their sources. Modules that use all of their exports are not included,
+    /// so the result can be passed to the `usedExports` option as it is.
+    pub fn used_exports(&self, entries: &[ModuleId]) -> AHashMap<ModuleId, Vec<String>> {
+        let mut used = self
+            .modules()
+            .map(|module| (module.id.clone(), UsedExports::Names(Vec::new())))
+            .collect::<AHashMap<ModuleId, UsedExports>>();
+
+        for entry in entries {
+            if let Some(used_exports) = used.get_mut(entry) {
+                *used_exports = UsedExports::All;
+            }
+        }
+
+        for module in self.modules() {
+            match self.usages.get(&module.id) {
+                Some(usage) => {
+                    for (src, used_exports) in &usage.imports {
+                        if let Some(dep_used_exports) = used.get_mut(src) {
+                            dep_used_exports.merge(used_exports);
+                        }
+                    }
+                }
+                None => {
+                    for dep in &module.deps {
+                        if let Some(dep_used_exports) = used.get_mut(dep) {
+                            *dep_used_exports = UsedExports::All;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Propagates the used exports through the re-exports until nothing changes.
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for id in &self.order {
+                let (Some(usage), Some(used_exports)) =
+                    (self.usages.get(id), used.get(id).cloned())
+                else {
+                    continue;
+                };
+
+                for (src, re_exported) in re_exported_usages(usage, &used_exports) {
+                    if let Some(dep_used_exports) = used.get_mut(&src) {
+                        changed |= dep_used_exports.merge(&re_exported);
+                    }
+                }
+            }
+        }
+
+        self.order
+            .iter()
+            .filter_map(|id| match used.remove(id) {
+                Some(UsedExports::Names(names)) => Some((id.clone(), names)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Collects the dependency sources of the program.
+///
+/// Sources are mapped by `paths`, so the result can be used as the dependencies of `ModuleGraph`.
+/// URL dependencies (workers and assets) are not included (see `collect_url_deps`).
+///
+/// ```js
+/// import foo from './foo'; // './foo'
+/// export * from './bar'; // './bar'
+/// require('./baz'); // './baz'
+/// import('./qux'); // './qux'
+/// ```
+pub fn collect_deps(
+    program: &Program,
+    paths: &Option<AHashMap<String, String>>,
+    unresolved_ctxt: SyntaxContext,
+) -> Vec<String> {
+    collect_all_deps(program, paths, &Externals::default(), unresolved_ctxt).deps
+}
+
+/// Collects the URL dependencies (workers and assets) of the program.
+///
+/// Sources are mapped by `paths` and expected to be registered to the registry as URLs.
+///
+/// ```js
+/// new Worker(new URL('./worker.js', import.meta.url)); // Worker './worker.js'
+/// new URL('./logo.png', import.meta.url); // Asset './logo.png'
+/// ```
+pub fn collect_url_deps(
+    program: &Program,
+    paths: &Option<AHashMap<String, String>>,
+    unresolved_ctxt: SyntaxContext,
+) -> Vec<UrlDep> {
+    collect_all_deps(program, paths, &Externals::default(), unresolved_ctxt).url_deps
+}
+
+/// Collects the usage of the dependencies' exports of the program.
+///
+/// Sources are mapped by `paths` (external dependencies are not included).
+/// Pass the result to `ModuleGraph::set_export_usage` to compute the used exports of the graph.
+pub fn collect_export_usage(
+    program: &Program,
+    paths: &Option<AHashMap<String, String>>,
+    unresolved_ctxt: SyntaxContext,
+) -> ExportUsage {
+    collect_all_deps(program, paths, &Externals::default(), unresolved_ctxt).usage
+}
+
+/// Dependencies collected from the program.
+#[derive(Debug, Default)]
+pub(crate) struct CollectedDeps {
+    /// Module dependency sources (mapped by `paths`)
+    pub deps: Vec<String>,
+    /// URL dependencies
+    pub url_deps: Vec<UrlDep>,
+    /// External dependency sources (not mapped)
+    pub externals: Vec<String>,
+    /// Usage of the dependencies' exports
+    pub usage: ExportUsage,
+}
+
+/// Collects the module dependency sources, the URL dependencies
+/// and the external dependency sources of the program.
+pub(crate) fn collect_all_deps(
+    program: &Program,
+    paths: &Option<AHashMap<String, String>>,
+    externals: &Externals,
+    unresolved_ctxt: SyntaxContext,
+) -> CollectedDeps {
+    let idents = Idents::default();
+    let ctx_ident = idents.ctx_ident();
+    let mut program = program.clone();
+    let unresolved_ctxt = ensure_resolved(&mut program, unresolved_ctxt);
+    let kind = detect_resolved_module_kind(&program, unresolved_ctxt);
+    let mut collector = create_collector(
+        unresolved_ctxt,
+        false,
+        kind,
+        &ctx_ident,
+        &idents,
+        paths,
+        externals,
+    );
+
+    match &mut program {
+        Program::Module(module) => module.visit_mut_children_with(&mut collector),
+        Program::Script(script) => script.visit_mut_with(&mut collector),
+    }
+
+    CollectedDeps::from_collector(&collector)
+}
+
+impl CollectedDeps {
+    /// Returns the dependencies collected by the module collector.
+    ///
+    /// The transformer calls this with its own collector,
+    /// so the dependencies are collected within the same pass.
+    pub(crate) fn from_collector(collector: &ModuleCollector) -> Self {
+        let mut deps = Vec::new();
+        let mut url_deps = Vec::new();
+        let mut usage = ExportUsage::default();
+
+        for dep in &collector.deps {
+            match dep {
+                Dep::Url(url_dep) => {
+                    if !url_deps.contains(url_dep) {
+                        url_deps.push(url_dep.clone());
+                    }
+                }
+                dep if collector.is_external(dep.src()) => {}
+                dep => {
+                    let used_exports = match dep {
+                        Dep::Base(base_dep) => base_dep
+                            .members
+                            .iter()
+                            .map(|member| member.name.clone())
+                            .collect::<Option<Vec<String>>>()
+                            .map_or(UsedExports::All, UsedExports::Names),
+                        _ => UsedExports::All,
+                    };
+
+                    deps.push(dep.src().to_string());
+                    add_used_exports(&mut usage.imports, dep.src(), used_exports);
+                }
+            }
+        }
+
+        for exp in &collector.exps {
+            match exp {
+                Exp::ReExportNamed(exp) if !collector.is_external(&exp.src) => {
+                    deps.push(exp.src.clone());
+                    usage
+                        .re_exports
+                        .extend(exp.members.iter().map(|member| ReExport {
+                            name: member.name.clone(),
+                            src: exp.src.clone(),
+                            orig: Some(member.orig.clone()),
+                        }));
+                }
+                Exp::ReExportAll(exp) if !collector.is_external(&exp.src) => {
+                    deps.push(exp.src.clone());
+
+                    match &exp.alias {
+                        Some(name) => usage.re_exports.push(ReExport {
+                            name: name.clone(),
+                            src: exp.src.clone(),
+                            orig: None,
+                        }),
+                        None => usage.star_re_exports.push(exp.src.clone()),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            deps: dedup(deps),
+            url_deps,
+            externals: collector.external_srcs.clone(),
+            usage,
+        }
+    }
+}
+
+/// Adds the used exports of the source.
+fn add_used_exports(
+    imports: &mut Vec<(ModuleId, UsedExports)>,
+    src: &str,
+    used_exports: UsedExports,
+) {
+    match imports.iter_mut().find(|(import_src, _)| import_src == src) {
+        Some((_, import_used_exports)) => {
+            import_used_exports.merge(&used_exports);
+        }
+        None => imports.push((src.to_string(), used_exports)),
+    }
+}
+
+/// Returns the used exports of the re-exported sources.
+///
+/// Names that are not re-exported explicitly may come from the re-export all sources
+/// (the `default` export is never re-exported by them).
+fn re_exported_usages(
+    usage: &ExportUsage,
+    used_exports: &UsedExports,
+) -> Vec<(ModuleId, UsedExports)> {
+    let mut usages = Vec::new();
+
+    for re_export in &usage.re_exports {
+        if let UsedExports::Names(names) = used_exports {
+            if !names.contains(&re_export.name) {
+                continue;
+            }
+        }
+
+        let re_exported = match &re_export.orig {
+            Some(orig) => UsedExports::Names(vec![orig.clone()]),
+            None => UsedExports::All,
+        };
+
+        usages.push((re_export.src.clone(), re_exported));
+    }
+
+    let star_used_exports = match used_exports {
+        UsedExports::All => UsedExports::All,
+        UsedExports::Names(names) => UsedExports::Names(
+            names
+                .iter()
+                .filter(|name| {
+                    *name != "default" && !usage.re_exports.iter().any(|re| &re.name == *name)
+                })
+                .cloned()
+                .collect(),
+        ),
+    };
+
+    for src in &usage.star_re_exports {
+        usages.push((src.clone(), star_used_exports.clone()));
+    }
+
+    usages
+}
+
+/// Removes duplicated values while keeping the order.
+fn dedup(values: Vec<String>) -> Vec<String> {
+    let mut seen = AHashSet::default();
+
+    values
+        .into_iter()
+        .filter(|value| seen.insert(value.clone()))
+        .collect()
+}
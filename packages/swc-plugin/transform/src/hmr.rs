@@ -0,0 +1,74 @@
+use std::collections::VecDeque;
+
+use swc_core::common::collections::AHashSet;
+
+use crate::graph::{ModuleGraph, ModuleId};
+
+/// Result of the HMR invalidation planning.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdatePlan {
+    /// Re-register the modules and notify the boundaries.
+    ///
+    /// Since `global.__modules.register(id)` overrides the existing module context,
+    /// re-executing the modules in order is enough to replace their exports.
+    Update {
+        /// Modules to re-execute (dependencies come first)
+        modules: Vec<ModuleId>,
+        /// Modules that accept the update
+        boundaries: Vec<ModuleId>,
+    },
+    /// The update can't be applied without reloading the whole application.
+    FullReload,
+}
+
+/// Plans an update for the changed modules.
+///
+/// Starting from each changed module, the invalidation is propagated to its dependents
+/// until it reaches a module that accepts the update (boundary).
+/// If the propagation reaches a module that has no dependents (eg. entry module)
+/// without passing through any boundary, a full reload is required.
+///
+/// ```text
+/// // `*` marks the boundary
+///
+/// entry <- App* <- Button <- (changed) theme
+///
+/// // Update { modules: [theme, Button, App], boundaries: [App] }
+/// ```
+pub fn plan_update(graph: &ModuleGraph, changed: &[ModuleId]) -> UpdatePlan {
+    let mut invalidated = Vec::new();
+    let mut boundaries = Vec::new();
+    let mut visited = AHashSet::default();
+    let mut queue = changed.iter().collect::<VecDeque<_>>();
+
+    while let Some(id) = queue.pop_front() {
+        if !visited.insert(id) {
+            continue;
+        }
+
+        // Unknown module. Can't determine its dependents.
+        let Some(module) = graph.get_module(id) else {
+            return UpdatePlan::FullReload;
+        };
+
+        invalidated.push(id.clone());
+
+        if module.accept {
+            boundaries.push(id.clone());
+            continue;
+        }
+
+        let dependents = graph.dependents_of(id);
+
+        if dependents.is_empty() {
+            return UpdatePlan::FullReload;
+        }
+
+        queue.extend(dependents);
+    }
+
+    UpdatePlan::Update {
+        modules: graph.sort_by_deps(&invalidated),
+        boundaries,
+    }
+}
@@ -0,0 +1,154 @@
+use swc_core::{
+    atoms::Atom,
+    common::collections::AHashSet,
+    ecma::{
+        ast::*,
+        utils::private_ident,
+        visit::{noop_visit_type, Visit, VisitWith},
+    },
+};
+
+use crate::options::Identifiers;
+
+/// Generated identifiers of the module.
+///
+/// Names are resolved once per module so that they never collide with
+/// the identifiers declared or referenced in the source (including unresolved globals).
+#[derive(Debug, Clone)]
+pub struct Idents {
+    context: Atom,
+    default: Atom,
+    module: Atom,
+    system_export: Atom,
+    system_context: Atom,
+    system_key: Atom,
+    system_temp: Atom,
+}
+
+impl Idents {
+    /// Resolves the generated identifier names that don't collide with the used symbols
+    pub fn new(identifiers: &Identifiers, used_syms: &AHashSet<Atom>) -> Self {
+        Self {
+            context: to_unique_sym(&identifiers.context, used_syms),
+            default: to_unique_sym(&identifiers.default, used_syms),
+            module: to_unique_sym(&identifiers.module, used_syms),
+            system_export: to_unique_sym("_export", used_syms),
+            system_context: to_unique_sym("_context", used_syms),
+            system_key: to_unique_sym("__key", used_syms),
+            system_temp: to_unique_sym("__tmp", used_syms),
+        }
+    }
+
+    /// Returns a module context identifier.
+    ///
+    /// ```js
+    /// // Code
+    /// __context;
+    /// ```
+    pub fn ctx_ident(&self) -> Ident {
+        private_ident!(self.context.clone())
+    }
+
+    /// Returns a binding identifier for the default export.
+    ///
+    /// ```js
+    /// // Code
+    /// __default;
+    /// ```
+    pub fn anonymous_default_binding_ident(&self) -> Ident {
+        private_ident!(self.default.clone())
+    }
+
+    /// Returns a module identifier.
+    ///
+    /// ```js
+    /// // Code
+    /// __mod;
+    /// ```
+    pub fn mod_ident(&self) -> Ident {
+        private_ident!(self.module.clone())
+    }
+
+    /// Returns a SystemJS export function identifier.
+    ///
+    /// ```js
+    /// // Code
+    /// _export;
+    /// ```
+    pub fn system_export_ident(&self) -> Ident {
+        private_ident!(self.system_export.clone())
+    }
+
+    /// Returns a SystemJS context identifier.
+    ///
+    /// ```js
+    /// // Code
+    /// _context;
+    /// ```
+    pub fn system_context_ident(&self) -> Ident {
+        private_ident!(self.system_context.clone())
+    }
+
+    /// Returns a key identifier of the SystemJS re-export all statements.
+    ///
+    /// ```js
+    /// // Code
+    /// for (var __key in __mod) { ... }
+    /// ```
+    pub fn system_key_ident(&self) -> Ident {
+        private_ident!(self.system_key.clone())
+    }
+
+    /// Returns a temporary identifier of the SystemJS postfix updates.
+    ///
+    /// ```js
+    /// // Code
+    /// (__tmp = foo++, _export("foo", foo), __tmp);
+    /// ```
+    pub fn system_temp_ident(&self) -> Ident {
+        private_ident!(self.system_temp.clone())
+    }
+}
+
+impl Default for Idents {
+    fn default() -> Self {
+        Self::new(&Identifiers::default(), &AHashSet::default())
+    }
+}
+
+/// Collects all identifier symbols used in the node.
+pub fn collect_used_syms<N: VisitWith<UsedSymCollector>>(node: &N) -> AHashSet<Atom> {
+    let mut collector = UsedSymCollector::default();
+    node.visit_with(&mut collector);
+    collector.syms
+}
+
+#[derive(Default)]
+pub struct UsedSymCollector {
+    syms: AHashSet<Atom>,
+}
+
+impl Visit for UsedSymCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.syms.insert(ident.sym.clone());
+    }
+}
+
+/// Returns a symbol of the name that doesn't collide with the used symbols.
+///
+/// The name is prefixed with `_` until no used symbol is the name itself
+/// or the name followed by digits (eg. `__mod`, `__mod1` -> `___mod`).
+fn to_unique_sym(prefix: &str, used_syms: &AHashSet<Atom>) -> Atom {
+    let mut sym = prefix.to_string();
+
+    while used_syms.iter().any(|used| {
+        used.strip_prefix(sym.as_str())
+            .is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit()))
+    }) {
+        sym.insert(0, '_');
+    }
+
+    sym.into()
+}
@@ -7,22 +7,57 @@ use swc_core::{
 };
 use transformer::GlobalModuleTransformer;
 
+#[cfg(feature = "compiler")]
+pub use bundler::{bundle, Bundle};
+#[cfg(feature = "compiler")]
+pub use compiler::{transform, ModuleSource, TransformError, TransformOutput};
+#[cfg(feature = "compiler")]
+pub use graph::{
+    collect_deps, collect_export_usage, collect_url_deps, ExportUsage, ModuleGraph, ModuleId,
+    ModuleNode, ReExport, UsedExports,
+};
+#[cfg(feature = "compiler")]
+pub use hmr::{plan_update, UpdatePlan};
+pub use models::{UrlDep, UrlDepKind};
+pub use module_kind::{detect_module_kind, ModuleKind};
+pub use options::{Externals, Identifiers, Options, OutputFormat, RegisterPlacement};
+#[cfg(feature = "compiler")]
+pub use update::{emit_update, UpdateChunk, UPDATE_HEADER_PREFIX};
+
 pub fn global_modules(
     id: String,
     runtime: bool,
     paths: Option<AHashMap<String, String>>,
+    options: Options,
     unresolved_ctxt: SyntaxContext,
 ) -> impl VisitMut + Pass {
     visit_mut_pass(GlobalModuleTransformer::new(
         id,
         runtime,
         paths,
+        options,
         unresolved_ctxt,
     ))
 }
 
+mod bindings;
+#[cfg(feature = "compiler")]
+mod bundler;
+#[cfg(feature = "compiler")]
+mod compiler;
+#[cfg(feature = "compiler")]
+mod graph;
+#[cfg(feature = "compiler")]
+mod hmr;
+mod idents;
 mod models;
 mod module_builder;
 mod module_collector;
+mod module_kind;
+mod options;
+mod scope;
+mod system_module_builder;
 mod transformer;
+#[cfg(feature = "compiler")]
+mod update;
 mod utils;
@@ -1,12 +1,12 @@
-use swc_core::{
-    common::DUMMY_SP,
-    ecma::{ast::*, utils::ExprFactory},
-};
+use swc_core::{atoms::Atom, common::DUMMY_SP, ecma::ast::*};
 
-use crate::utils::ast::*;
+use crate::{bindings::LocalBindings, utils::ast::*};
 
 // Dependency
+//
+// Sources of the runtime and URL dependencies are read by the compiler APIs only.
 #[derive(Debug)]
+#[cfg_attr(not(feature = "compiler"), allow(dead_code))]
 pub enum Dep {
     /// Dependency
     ///
@@ -23,7 +23,14 @@ pub enum Dep {
     /// // ESModule
     /// import(...);
     /// ```
-    Runtime,
+    Runtime(RuntimeDep),
+    /// URL dependency (workers and assets)
+    ///
+    /// ```js
+    /// new Worker(new URL('...', import.meta.url));
+    /// new URL('...', import.meta.url);
+    /// ```
+    Url(UrlDep),
 }
 
 impl Dep {
@@ -33,8 +40,23 @@ impl Dep {
     }
 
     /// Creates a new runtime dependency
-    pub fn runtime() -> Self {
-        Dep::Runtime
+    pub fn runtime(src: String) -> Self {
+        Dep::Runtime(RuntimeDep { src })
+    }
+
+    /// Creates a new URL dependency
+    pub fn url(src: String, kind: UrlDepKind) -> Self {
+        Dep::Url(UrlDep { src, kind })
+    }
+
+    /// Returns the (mapped) source of the dependency
+    #[cfg_attr(not(feature = "compiler"), allow(dead_code))]
+    pub fn src(&self) -> &str {
+        match self {
+            Dep::Base(BaseDep { src, .. }) => src,
+            Dep::Runtime(RuntimeDep { src }) => src,
+            Dep::Url(UrlDep { src, .. }) => src,
+        }
     }
 }
 
@@ -46,11 +68,38 @@ pub struct BaseDep {
     pub members: Vec<DepMember>,
 }
 
+#[derive(Debug)]
+#[cfg_attr(not(feature = "compiler"), allow(dead_code))]
+pub struct RuntimeDep {
+    /// Source
+    pub src: String,
+}
+
+/// URL dependency of the module.
+///
+/// Referenced by the URL (not evaluated as a module of the registry).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlDep {
+    /// Source (mapped by `paths`)
+    pub src: String,
+    /// Kind
+    pub kind: UrlDepKind,
+}
+
+/// Kind of the URL dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlDepKind {
+    /// Worker script (`new Worker(new URL(...))`, `new SharedWorker(new URL(...))`)
+    Worker,
+    /// Asset (`new URL(...)`)
+    Asset,
+}
+
 #[derive(Debug)]
 pub struct DepMember {
     /// Identifier
     pub ident: Ident,
-    /// Name
+    /// Imported name (`None` for the namespace import)
     pub name: Option<String>,
 }
 
@@ -67,8 +116,8 @@ impl DepMember {
     /// ```
     pub fn into_obj_pat_prop(self) -> ObjectPatProp {
         match self.name {
-            Some(name) => obj_kv_prop(name.into(), self.ident.into()),
-            None => obj_assign_prop(self.ident.into()),
+            Some(name) if self.ident.sym != name.as_str() => obj_kv_prop(name.into(), self.ident),
+            _ => obj_assign_prop(self.ident),
         }
     }
 }
@@ -95,6 +144,7 @@ pub enum Exp {
     /// ```
     ReExportNamed(ReExportNamedExp),
 }
+
 #[derive(Debug)]
 pub struct BaseExp {
     /// Export members
@@ -109,26 +159,25 @@ impl BaseExp {
 
     /// Converts to ASTs
     ///
-    /// - `Vec<VarDeclarator>`
     /// - `Vec<PropOrSpread>`
     /// - `Vec<ExportSpecifier>`
-    pub fn into_asts(self) -> (Vec<VarDeclarator>, Vec<PropOrSpread>, Vec<ExportSpecifier>) {
+    ///
+    /// Export properties are getters to reference the local bindings lazily,
+    /// except for the hoisted function declarations.
+    pub fn into_asts(
+        self,
+        bindings: &LocalBindings,
+        compact: bool,
+    ) -> (Vec<PropOrSpread>, Vec<ExportSpecifier>) {
         let len = self.members.len();
 
-        // To declare binding variables
-        //
-        // ```js
-        // var __x, __x1, __x2;
-        // ```
-        let mut declarators = Vec::with_capacity(len);
-
         // To declare export properties
         //
         // ```js
         // context.exports({
-        //   "foo": __x,
-        //   "bar": __x1,
-        //   "baz": __x2,
+        //   "foo": foo, // Hoisted function declaration
+        //   get "bar"() { return bar; },
+        //   get "baz"() { return qux; },
         // });
         // ```
         let mut props = Vec::with_capacity(len);
@@ -136,25 +185,33 @@ impl BaseExp {
         // To declare export specifiers
         //
         // ```js
-        // export { foo as __x, bar as __x1, default as __x2 };
+        // export { foo as foo, bar as bar, qux as baz };
         // ```
         let mut specs = Vec::with_capacity(len);
 
         self.members.into_iter().for_each(|member| {
-            declarators.push(var_declarator(member.ident.clone().into(), None));
-            props.push(kv_prop(
-                member.name.clone().into(),
-                member.ident.clone().into(),
-            ));
+            let key: Atom = member.name.clone().into();
+            let hoisted = bindings.is_hoisted(&member.ident);
+
+            props.push(match (hoisted, compact) {
+                (true, true) => compact_prop(key, member.ident.clone()),
+                (true, false) => kv_prop(key, member.ident.clone().into()),
+                (false, true) => getter_prop(compact_prop_name(key), member.ident.clone().into()),
+                (false, false) => getter_prop(str_prop_name(key), member.ident.clone().into()),
+            });
             specs.push(ExportSpecifier::Named(ExportNamedSpecifier {
+                exported: if compact && member.ident.sym == member.name {
+                    None
+                } else {
+                    Some(to_module_export_name(&member.name))
+                },
                 orig: ModuleExportName::Ident(member.ident),
-                exported: Some(ModuleExportName::Ident(Ident::from(member.name))),
                 is_type_only: false,
                 span: DUMMY_SP,
             }));
         });
 
-        (declarators, props, specs)
+        (props, specs)
     }
 }
 
@@ -163,7 +220,7 @@ pub struct ReExportAllExp {
     /// Source
     pub src: String,
     /// Alias
-    pub alias: Option<Ident>,
+    pub alias: Option<String>,
 }
 
 impl ReExportAllExp {
@@ -173,31 +230,35 @@ impl ReExportAllExp {
     }
 
     /// Creates a new re-export all with alias
-    pub fn alias(src: String, ident: Ident) -> Self {
+    pub fn alias(src: String, name: String) -> Self {
         Self {
             src,
-            alias: Some(ident),
+            alias: Some(name),
         }
     }
 
     /// Converts to export properties
     ///
+    /// Returns `None` for the re-export all without alias
+    /// (namespace exports of all sources are resolved at once by `ctx_ident.exports.ns(...)`).
+    ///
     /// ```js
     /// {
     ///   // Re-export with alias
-    ///   "foo": ctx_ident.exports.ns(mod_ident),
-    ///   // Re-export all
-    ///   ...ctx_ident.exports.ns(mod_ident),
+    ///   // Namespace object includes the `default` export.
+    ///   "foo": mod_ident,
+    ///   // Compact mode (registered with the other exports before the module is required)
+    ///   get foo() { return mod_ident; },
     /// }
     /// ```
-    pub fn to_exp_props(&self, ctx_ident: &Ident, mod_ident: Ident) -> PropOrSpread {
-        match &self.alias {
-            Some(ident) => kv_prop(
-                ident.sym.clone(),
-                to_ns_export(ctx_ident.clone().into(), mod_ident.into()),
-            ),
-            None => spread_prop(to_ns_export(ctx_ident.clone().into(), mod_ident.into())),
-        }
+    pub fn to_exp_props(&self, mod_ident: Ident, compact: bool) -> Option<PropOrSpread> {
+        self.alias.as_ref().map(|name| {
+            if compact {
+                getter_prop(compact_prop_name(name.as_str().into()), mod_ident.into())
+            } else {
+                kv_prop(name.as_str().into(), mod_ident.into())
+            }
+        })
     }
 }
 
@@ -206,7 +267,7 @@ pub struct ReExportNamedExp {
     /// Source
     pub src: String,
     /// Members
-    pub members: Vec<ExpMember>,
+    pub members: Vec<ReExpMember>,
 }
 
 impl ReExportNamedExp {
@@ -214,28 +275,25 @@ impl ReExportNamedExp {
     ///
     /// ```js
     /// {
-    ///   "foo": mod_ident,
-    ///   "bar": mod_ident,
+    ///   get "foo"() { return mod_ident.foo; },
+    ///   get "bar"() { return mod_ident.baz; },
+    ///   get "baz"() { return mod_ident["string name"]; },
     /// }
-    pub fn to_exp_props(&self, mod_ident: Ident) -> Vec<PropOrSpread> {
+    /// ```
+    pub fn to_exp_props(&self, mod_ident: Ident, compact: bool) -> Vec<PropOrSpread> {
         self.members
             .iter()
             .map(|member| {
-                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        sym: member.name.clone().into(),
-                        span: DUMMY_SP,
-                    }),
-                    value: Box::new(
-                        mod_ident
-                            .clone()
-                            .make_member(IdentName {
-                                sym: member.ident.sym.clone(),
-                                ..Default::default()
-                            })
-                            .into(),
-                    ),
-                })))
+                let key: Atom = member.name.clone().into();
+
+                getter_prop(
+                    if compact {
+                        compact_prop_name(key)
+                    } else {
+                        str_prop_name(key)
+                    },
+                    member_by_name(mod_ident.clone(), &member.orig),
+                )
             })
             .collect()
     }
@@ -256,47 +314,17 @@ impl ExpMember {
     }
 }
 
-/// Binding data for references the module exports.
-///
-/// ```js
-/// // Code
-/// export const foo = 1;
-/// export default bar;
-/// export * from './baz';
-/// ```
-///
-/// `ExpBinding` is data struct for the transform to following code:
-///
-/// ```js
-/// // Declaration of binding identifiers
-/// var __x, __x1, __x2;
-///
-/// // Binding export members into binding identifiers
-/// import * as __mod from './baz';
-/// __x = foo;
-/// __x1 = bar;
-/// __x2 = __mod;
-///
-/// // Collected `ExpBinding`:
-/// // { binding_ident: __x, expr: foo }
-/// // { binding_ident: __x1, expr: bar }
-/// // { binding_ident: __x2, expr: __mod }
-/// ```
 #[derive(Debug)]
-pub struct ExpBinding {
-    /// Binding identifier
-    pub binding_ident: Ident,
-    /// Expression
-    pub expr: Expr,
+pub struct ReExpMember {
+    /// Name of the source module's export
+    pub orig: String,
+    /// Name
+    pub name: String,
 }
 
-impl ExpBinding {
-    /// Converts to assignment expression
-    ///
-    /// ```js
-    /// binding_ident = expr;
-    /// ```
-    pub fn to_assign_expr(self) -> Expr {
-        assign_expr(self.binding_ident, self.expr).into()
+impl ReExpMember {
+    /// Creates a new re-export member
+    pub fn new(orig: String, name: String) -> Self {
+        Self { orig, name }
     }
 }
@@ -1,211 +1,371 @@
 use crate::{
-    models::{Dep, Exp},
+    bindings::LocalBindings,
+    idents::Idents,
+    models::{BaseExp, Dep, Exp},
     module_collector::ModuleCollector,
+    options::RegisterPlacement,
     utils::ast::*,
     utils::presets::*,
 };
 use swc_core::{
-    common::{SyntaxContext, DUMMY_SP},
+    common::DUMMY_SP,
     ecma::{ast::*, utils::ExprFactory},
 };
 
 pub struct ModuleBuilder<'a> {
     /// Context identifier
     ctx_ident: &'a Ident,
+    /// Generated identifiers
+    idents: &'a Idents,
+    /// Compact output
+    compact: bool,
+    /// Exports used at runtime (`None` if all exports are used)
+    used_exports: Option<&'a Vec<String>>,
     /// Imports statements for re-exports bindings
     bind_imports: Vec<ModuleItem>,
+    /// Sources of the external dependencies
+    external_srcs: Vec<String>,
+    /// Host accessor of the external dependencies
+    accessor: Option<String>,
+    /// Import statements for re-exports bindings that are kept in the runtime phase
+    /// (external dependencies without the host accessor)
+    runtime_bind_imports: Vec<ModuleItem>,
     /// global module's `require` call statements
     req_calls: Vec<Stmt>,
-    /// Binding statement
-    ///
-    /// ```js
-    /// // Actual module statements
-    /// // <- Binding statement
-    /// // Exports call expression
-    /// ```
-    binding_stmt: Option<Stmt>,
+    /// Statements to run before the module body
+    hoisted_stmts: Vec<Stmt>,
     /// Export properties
     ///
+    /// Registered before the module body executes,
+    /// so the circular importers can access the exports as soon as they are initialized.
+    ///
     /// ```js
     /// // Vector of `PropOrSpread`
-    /// // key: foo, value: __x
-    /// // key: bar, value: __x1
-    /// // key: baz, value: __x2
+    /// // key: foo, value: foo (hoisted function declaration)
+    /// // key: bar, value: bar
+    /// // key: baz, value: __mod.baz
     ///
     /// // Will be transformed into
     /// context.exports({
-    ///   "foo": __x,
-    ///   "bar": __x1,
-    ///   "baz": __x2,
+    ///   "foo": foo,
+    ///   get "bar"() { return bar; },
+    ///   get "baz"() { return __mod.baz; },
     /// });
     /// ```
     pub exp_props: Vec<PropOrSpread>,
-    /// Export var declarators
+    /// Namespace export properties
+    ///
+    /// Registered after the dependencies are required
+    /// because they are evaluated eagerly.
+    /// (compact mode: registered as lazy getters of the export properties)
     ///
     /// ```js
-    /// // Vector of `VarDeclarator`
-    /// // "foo", "bar", "baz"
+    /// // Will be transformed into
+    /// context.exports({
+    ///   "foo": context.exports.ns(__mod),
+    /// });
+    /// ```
+    pub ns_exp_props: Vec<PropOrSpread>,
+    /// Module identifiers of the re-export all statements
+    ///
+    /// All of them are registered with a single exports call
+    /// instead of spreading into the object (which reads all values eagerly).
+    /// The runtime excludes the names exported by more than one module (ambiguous names),
+    /// and the explicit exports that are registered earlier are never overwritten.
     ///
+    /// ```js
     /// // Will be transformed into
-    /// var foo, bar, baz;
+    /// context.exports(function () {
+    ///   return context.exports.ns(__mod1, __mod2);
+    /// });
+    ///
+    /// // Compact mode (shares the exports call with the export properties)
+    /// context.exports(function () {
+    ///   return { ... };
+    /// }, __mod1, __mod2);
     /// ```
-    pub exp_decls: Vec<VarDeclarator>,
+    pub ns_mods: Vec<Ident>,
     /// Export specifiers
     ///
     /// ```js
     /// // Vector of `ExportSpecifier`
-    /// // name: "foo", ident: __x
-    /// // name: "bar", ident: __x1
-    /// // name: "default", ident: __x2
+    /// // name: "foo", ident: foo
+    /// // name: "bar", ident: baz
+    /// // name: "default", ident: __default
     ///
     /// // Will be transformed into
-    /// export { foo as __x, bar as __x1, default as __x2 };
+    /// export { foo as foo, baz as bar, __default as default };
     /// ```
     pub exp_specs: Vec<ExportSpecifier>,
 }
 
 impl<'a> ModuleBuilder<'a> {
-    pub fn new(ctx_ident: &'a Ident) -> Self {
+    pub fn new(
+        ctx_ident: &'a Ident,
+        idents: &'a Idents,
+        compact: bool,
+        used_exports: Option<&'a Vec<String>>,
+    ) -> Self {
         Self {
             ctx_ident,
+            idents,
+            compact,
+            used_exports,
             bind_imports: Vec::new(),
+            external_srcs: Vec::new(),
+            accessor: None,
+            runtime_bind_imports: Vec::new(),
             req_calls: Vec::new(),
-            binding_stmt: None,
+            hoisted_stmts: Vec::new(),
             exp_props: Vec::new(),
-            exp_decls: Vec::new(),
+            ns_exp_props: Vec::new(),
+            ns_mods: Vec::new(),
             exp_specs: Vec::new(),
         }
     }
 
-    /// Collects ASTs from the collected dependencies, exports, and bindings
-    pub fn collect(&mut self, collector: &mut ModuleCollector) {
+    /// Collects ASTs from the collected dependencies and exports
+    pub fn collect(&mut self, collector: &mut ModuleCollector, bindings: &LocalBindings) {
+        self.external_srcs = collector.external_srcs.clone();
+        self.accessor = collector.externals.accessor.clone();
         self.collect_deps(collector);
-        self.collect_exps(collector);
-        self.collect_bindings(collector);
+        self.collect_exps(collector, bindings);
+        self.hoisted_stmts = collector.take_hoisted_stmts();
+
+        // Aliases the context's module exports to the CommonJS module exports object
+        // before the module body mutates it.
+        if collector.cjs_exports {
+            self.hoisted_stmts.insert(
+                0,
+                alias_cjs_module_exports_stmt(self.ctx_ident, collector.unresolved_ctxt),
+            );
+        }
     }
 
     /// Collects ASTs from the collected dependencies
     fn collect_deps(&mut self, collector: &mut ModuleCollector) {
-        collector.take_deps().into_iter().for_each(|dep| match dep {
-            Dep::Base(base_dep) => {
-                let src = base_dep.src;
-                let require_props = base_dep
-                    .members
-                    .into_iter()
-                    .map(|member| member.into_obj_pat_prop())
-                    .collect::<Vec<ObjectPatProp>>();
-
-                self.req_calls.push(
-                    VarDecl {
-                        kind: VarDeclKind::Const,
-                        decls: vec![var_declarator(
-                            Pat::Object(ObjectPat {
-                                props: require_props,
-                                optional: false,
-                                type_ann: None,
-                                span: DUMMY_SP,
-                            }),
-                            Some(Box::new(require_call(src.into()))),
-                        )],
-                        ..Default::default()
-                    }
-                    .into(),
-                )
+        for dep in collector.take_deps() {
+            let Dep::Base(base_dep) = dep else {
+                continue;
+            };
+
+            // Side-effect only imports have nothing to bind
+            if base_dep.members.is_empty() {
+                continue;
             }
-            _ => {}
-        });
+
+            let src = base_dep.src;
+
+            // Kept as the native import statement
+            if self.keeps_import(&src) {
+                continue;
+            }
+
+            let require_props = base_dep
+                .members
+                .into_iter()
+                .map(|member| member.into_obj_pat_prop())
+                .collect::<Vec<ObjectPatProp>>();
+
+            self.req_calls.push(
+                VarDecl {
+                    kind: VarDeclKind::Const,
+                    decls: vec![var_declarator(
+                        Pat::Object(ObjectPat {
+                            props: require_props,
+                            optional: false,
+                            type_ann: None,
+                            span: DUMMY_SP,
+                        }),
+                        Some(Box::new(self.to_require_call(src))),
+                    )],
+                    ..Default::default()
+                }
+                .into(),
+            );
+        }
     }
 
     /// Collects ASTs from the collected exports
-    fn collect_exps(&mut self, collector: &mut ModuleCollector) {
+    fn collect_exps(&mut self, collector: &mut ModuleCollector, bindings: &LocalBindings) {
         collector.take_exps().into_iter().for_each(|exp| match exp {
             Exp::Base(exp) => {
-                let (decls, props, specs) = exp.into_asts();
+                // Unused exports are exported by the export specifiers only.
+                let (used, unused): (Vec<_>, Vec<_>) = exp
+                    .members
+                    .into_iter()
+                    .partition(|member| self.is_used(&member.name));
+                let (props, specs) = BaseExp::new(used).into_asts(bindings, self.compact);
+                let (_, unused_specs) = BaseExp::new(unused).into_asts(bindings, self.compact);
 
-                self.exp_decls.extend(decls);
                 self.exp_props.extend(props);
                 self.exp_specs.extend(specs);
+                self.exp_specs.extend(unused_specs);
             }
-            Exp::ReExportNamed(re_export_named) => {
-                let mod_ident = mod_ident();
+            Exp::ReExportNamed(mut re_export_named) => {
+                let mod_ident = self.idents.mod_ident();
                 let src = re_export_named.src.clone();
                 let imp_stmt = to_import_namespace_stmt(mod_ident.clone(), src.clone());
-                let req_stmt = to_require_stmt(mod_ident.clone(), src.clone());
-                let exp_prop = re_export_named.to_exp_props(mod_ident);
 
-                self.bind_imports.push(imp_stmt);
-                self.req_calls.push(req_stmt);
+                re_export_named
+                    .members
+                    .retain(|member| self.is_used(&member.name));
+
+                let exp_prop = re_export_named.to_exp_props(mod_ident.clone(), self.compact);
+
+                self.bind_module(mod_ident, imp_stmt, src);
                 self.exp_props.extend(exp_prop);
             }
             Exp::ReExportAll(re_export_all) => {
-                let mod_ident = mod_ident();
+                let mod_ident = self.idents.mod_ident();
                 let src = re_export_all.src.clone();
                 let imp_stmt = to_import_all_stmt(mod_ident.clone(), src.clone());
-                let req_stmt = to_require_stmt(mod_ident.clone(), src.clone());
-                let exp_prop = re_export_all.to_exp_props(collector.ctx_ident, mod_ident);
+                let exp_prop = re_export_all.to_exp_props(mod_ident.clone(), self.compact);
+
+                self.bind_module(mod_ident.clone(), imp_stmt, src);
 
-                self.bind_imports.push(imp_stmt);
-                self.req_calls.push(req_stmt);
-                self.exp_props.push(exp_prop);
+                match (exp_prop, &re_export_all.alias) {
+                    // Lazy getter in compact mode
+                    (Some(prop), Some(name)) if self.is_used(name) && self.compact => {
+                        self.exp_props.push(prop)
+                    }
+                    (Some(prop), Some(name)) if self.is_used(name) => self.ns_exp_props.push(prop),
+                    (Some(_), _) => {}
+                    (None, _) => self.ns_mods.push(mod_ident),
+                }
             }
         });
     }
 
-    /// Collects bindings from the collector and
-    /// creates a statement that assigns them to the each binding
+    /// Returns `true` if the export is used at runtime
+    fn is_used(&self, name: &str) -> bool {
+        self.used_exports.map_or(true, |used_exports| {
+            used_exports.iter().any(|used| used == name)
+        })
+    }
+
+    /// Returns `true` if the import statement of the source is kept in the runtime phase
+    /// (external dependencies without the host accessor)
+    fn keeps_import(&self, src: &str) -> bool {
+        self.accessor.is_none() && self.external_srcs.iter().any(|external| external == src)
+    }
+
+    /// Returns the require call expression of the source
     ///
     /// ```js
-    /// // binding_stmt
-    /// __x = foo, __x1 = bar, __x2 = baz;
+    /// global.__modules.require(src);
+    /// accessor.require(src); // External dependencies
     /// ```
-    fn collect_bindings(&mut self, collector: &mut ModuleCollector) {
-        let bindings = collector.take_bindings();
+    fn to_require_call(&self, src: String) -> Expr {
+        match &self.accessor {
+            Some(accessor) if self.external_srcs.contains(&src) => {
+                host_require_call(accessor, src.into())
+            }
+            _ => require_call(src.into()),
+        }
+    }
 
-        if bindings.is_empty() {
-            return;
+    /// Binds the re-exported module to the module identifier
+    ///
+    /// - Bundle phase: import statement
+    /// - Runtime phase: require call statement
+    ///   (import statement for the external dependencies without the host accessor)
+    fn bind_module(&mut self, mod_ident: Ident, imp_stmt: ModuleItem, src: String) {
+        if self.keeps_import(&src) {
+            self.runtime_bind_imports.push(imp_stmt.clone());
+        } else {
+            self.req_calls.push(
+                self.to_require_call(src)
+                    .into_var_decl(VarDeclKind::Const, mod_ident.into())
+                    .into(),
+            );
         }
 
-        self.binding_stmt = Some(
-            Expr::Seq(SeqExpr {
-                exprs: bindings
-                    .into_iter()
-                    .map(|binding| Box::new(binding.to_assign_expr()))
-                    .collect::<Vec<Box<Expr>>>(),
-                ..Default::default()
-            })
-            .into_stmt(),
-        );
+        self.bind_imports.push(imp_stmt);
+    }
+
+    /// Returns the exports call statements
+    ///
+    /// - Exports call (before the dependencies are required)
+    /// - Namespace exports calls (after the dependencies are required)
+    ///
+    /// In compact mode, all exports are registered with a single exports call.
+    /// If the module re-exports all of other modules, the call is placed after the dependencies are required
+    /// because their names are resolved eagerly.
+    fn exports_calls(&mut self) -> (Option<Stmt>, Vec<Stmt>) {
+        if self.compact {
+            let props = std::mem::take(&mut self.exp_props);
+            let ns_mods = std::mem::take(&mut self.ns_mods);
+
+            if ns_mods.is_empty() {
+                return (
+                    (!props.is_empty())
+                        .then(|| exports_call(self.ctx_ident, obj_lit_expr(props)).into_stmt()),
+                    Vec::new(),
+                );
+            }
+
+            return (
+                None,
+                vec![exports_call_with_sources(
+                    self.ctx_ident,
+                    obj_lit_expr(props),
+                    ns_mods.into_iter().map(Into::into).collect(),
+                )
+                .into_stmt()],
+            );
+        }
+
+        let to_exports_call =
+            |definitions: Expr| exports_call(self.ctx_ident, definitions).into_stmt();
+        let to_props_exports_call = |props: Vec<PropOrSpread>| {
+            if props.is_empty() {
+                None
+            } else {
+                Some(to_exports_call(obj_lit_expr(props)))
+            }
+        };
+
+        let exports_call = to_props_exports_call(std::mem::take(&mut self.exp_props));
+        let ns_mods = std::mem::take(&mut self.ns_mods);
+        let ns_exports_calls = to_props_exports_call(std::mem::take(&mut self.ns_exp_props))
+            .into_iter()
+            .chain((!ns_mods.is_empty()).then(|| {
+                to_exports_call(to_ns_export(
+                    self.ctx_ident.clone(),
+                    ns_mods.into_iter().map(Into::into).collect(),
+                ))
+            }))
+            .collect();
+
+        (exports_call, ns_exports_calls)
     }
 
     /// Returns a list of statements that can be used to source type: 'module'
+    ///
+    /// ```js
+    /// // Imports (bundle phase, `RegisterPlacement::AfterImports`)
+    /// const __context = global.__modules.register(id);
+    /// // Hoisted statements
+    /// __context.exports(...);    // Exports call
+    /// // Imports (bundle phase, `RegisterPlacement::BeforeImports`)
+    /// // Require calls (runtime phase)
+    /// __context.exports(...);    // Namespace exports calls
+    /// // Module statements
+    /// // Exports (bundle phase)
+    /// ```
     pub fn build_module(
-        self,
-        id: &String,
+        mut self,
+        id: &str,
         runtime: bool,
-        orig_module: Vec<ModuleItem>,
+        placement: RegisterPlacement,
+        mut orig_module: Vec<ModuleItem>,
     ) -> Vec<ModuleItem> {
-        let exports_call = if self.exp_props.is_empty() {
-            None
-        } else {
-            Some(exports_call(&self.ctx_ident, self.exp_props).into_stmt())
-        };
-
-        let exp_var_decl = if self.exp_decls.len() > 0 {
-            Some(
-                Decl::Var(Box::new(VarDecl {
-                    decls: self.exp_decls,
-                    kind: VarDeclKind::Var,
-                    declare: false,
-                    span: DUMMY_SP,
-                    ctxt: SyntaxContext::default(),
-                }))
-                .into(),
-            )
-        } else {
-            None
-        };
+        let (exports_call, ns_exports_calls) = self.exports_calls();
+        let directives = take_directives(&mut orig_module, ModuleItem::as_stmt);
 
         let mut imports = Vec::new();
+        let mut runtime_imports = Vec::new();
         let mut exports = Vec::new();
         let mut stmts = vec![];
 
@@ -216,112 +376,98 @@ impl<'a> ModuleBuilder<'a> {
 
         orig_module.into_iter().for_each(|item| match item {
             ModuleItem::ModuleDecl(ref module_decl) => match module_decl {
-                ModuleDecl::Import(_) => imports.push(item),
+                ModuleDecl::Import(import_decl) => {
+                    if self.keeps_import(&import_decl.src.value) {
+                        runtime_imports.push(item.clone());
+                    }
+
+                    imports.push(item);
+                }
                 _ => exports.push(item),
             },
             ModuleItem::Stmt(ref stmt) if !matches!(stmt, Stmt::Empty(_)) => stmts.push(item),
             _ => {}
         });
 
-        let extra_stmts = self
-            .binding_stmt
-            .into_iter()
-            .chain(exports_call.into_iter())
-            .chain(exp_var_decl.into_iter())
-            .map(Into::into)
-            .collect::<Vec<ModuleItem>>();
-
-        if runtime {
-            let size =
-                1 /* context_decl */ + self.req_calls.len() + stmts.len() + extra_stmts.len();
-            let mut items = Vec::with_capacity(size);
+        let size = directives.len()
+            + imports.len()
+            + self.bind_imports.len()
+            + self.hoisted_stmts.len()
+            + self.req_calls.len()
+            + 2 // context_decl, exports_call
+            + ns_exports_calls.len()
+            + stmts.len()
+            + exports.len()
+            + 1; // exp_specs
+        let mut items = Vec::with_capacity(size);
+
+        items.extend(directives);
+
+        let imports = if runtime {
+            runtime_imports
+                .into_iter()
+                .chain(self.runtime_bind_imports)
+                .collect::<Vec<_>>()
+        } else {
+            imports.into_iter().chain(self.bind_imports).collect()
+        };
 
+        if placement == RegisterPlacement::AfterImports {
+            items.extend(imports);
             items.push(context_decl.into());
-            items.extend(self.req_calls.into_iter().map(|stmt| stmt.into()));
-            items.extend(stmts);
-            items.extend(extra_stmts);
-            items
+            items.extend(self.hoisted_stmts.into_iter().map(Into::into));
+            items.extend(exports_call.map(Into::into));
         } else {
-            let exp_specs_len = if self.exp_specs.len() > 0 { 1 } else { 0 };
-            let size = imports.len()
-                    + self.bind_imports.len()
-                    + 1 // context_decl
-                    + stmts.len()
-                    + extra_stmts.len()
-                    + exports.len()
-                    + exp_specs_len;
+            items.push(context_decl.into());
+            items.extend(self.hoisted_stmts.into_iter().map(Into::into));
+            items.extend(exports_call.map(Into::into));
+            items.extend(imports);
+        }
 
-            let mut items = Vec::with_capacity(size);
+        if runtime {
+            items.extend(self.req_calls.into_iter().map(Into::into));
+        }
 
-            items.extend(imports);
-            items.extend(self.bind_imports);
-            items.push(context_decl.into());
-            items.extend(stmts);
-            items.extend(extra_stmts);
+        items.extend(ns_exports_calls.into_iter().map(Into::into));
+        items.extend(stmts);
+
+        if !runtime {
             items.extend(exports);
 
-            if exp_specs_len > 0 {
+            if !self.exp_specs.is_empty() {
                 items.push(to_named_exps(self.exp_specs));
             }
-
-            items
         }
+
+        items
     }
 
     /// Returns a list of statements that can be used to source type: 'script'
-    pub fn build_script(self, id: &String, orig_script: Vec<Stmt>) -> Vec<Stmt> {
-        let mut size = self.req_calls.len() + orig_script.len();
-
-        let exports_call = if self.exp_props.is_empty() {
-            None
-        } else {
-            size += 1;
-            Some(exports_call(&self.ctx_ident, self.exp_props).into_stmt())
-        };
-
-        let exp_var_decl = if self.exp_decls.len() > 0 {
-            size += 1;
-            Some(
-                Decl::Var(Box::new(VarDecl {
-                    decls: self.exp_decls,
-                    kind: VarDeclKind::Var,
-                    declare: false,
-                    span: DUMMY_SP,
-                    ctxt: SyntaxContext::default(),
-                }))
-                .into(),
-            )
-        } else {
-            None
-        };
-
-        if self.binding_stmt.is_some() {
-            size += 1;
-        }
+    pub fn build_script(mut self, id: &str, mut orig_script: Vec<Stmt>) -> Vec<Stmt> {
+        let (exports_call, ns_exports_calls) = self.exports_calls();
+        let directives = take_directives(&mut orig_script, |stmt| Some(stmt));
 
         let context_decl = register_call(id).into_var_decl(
             VarDeclKind::Const,
             Pat::Ident(self.ctx_ident.clone().into()),
         );
 
-        let mut stmts = Vec::with_capacity(size + 1 /* context_decl */);
+        let mut stmts = Vec::with_capacity(
+            directives.len()
+                + self.hoisted_stmts.len()
+                + self.req_calls.len()
+                + ns_exports_calls.len()
+                + orig_script.len()
+                + 2, /* context_decl, exports_call */
+        );
 
+        stmts.extend(directives);
         stmts.push(context_decl.into());
+        stmts.extend(self.hoisted_stmts);
+        stmts.extend(exports_call);
         stmts.extend(self.req_calls);
+        stmts.extend(ns_exports_calls);
         stmts.extend(orig_script);
-
-        if let Some(binding_stmt) = self.binding_stmt {
-            stmts.push(binding_stmt);
-        }
-
-        if let Some(exports_call) = exports_call {
-            stmts.push(exports_call);
-        }
-
-        if let Some(exp_var_decl) = exp_var_decl {
-            stmts.push(exp_var_decl);
-        }
-
         stmts
     }
 }
@@ -1,33 +1,48 @@
 use std::mem;
 
 use swc_core::{
-    common::{collections::AHashMap, util::take::Take, Spanned, SyntaxContext},
+    common::{collections::AHashMap, util::take::Take, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::ExprFactory,
         visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
     },
     plugin::errors::HANDLER,
 };
 
 use crate::{
-    models::{Dep, Exp, ExpBinding},
+    idents::Idents,
+    models::{Dep, Exp, UrlDepKind},
+    module_kind::ModuleKind,
+    options::Externals,
     utils::{ast::*, helpers::to_mapped_src, presets::*},
 };
 
 pub struct ModuleCollector<'a> {
     /// Runtime phase flag
     pub runtime: bool,
+    /// Module kind
+    pub kind: ModuleKind,
+    /// Whether the module references the CommonJS module exports (`module.exports`, `exports`)
+    pub cjs_exports: bool,
+    /// Whether the visitor is inside of a scope that has its own `this`
+    /// (functions, classes, getters and setters)
+    in_this_scope: bool,
     /// Dependencies
     pub deps: Vec<Dep>,
     /// Exports
     pub exps: Vec<Exp>,
-    /// Export bindings
-    pub exp_bindings: Vec<ExpBinding>,
+    /// Statements to run before the module body
+    pub hoisted_stmts: Vec<Stmt>,
     /// Context identifier
     pub ctx_ident: &'a Ident,
+    /// Generated identifiers
+    pub idents: &'a Idents,
     /// Paths
     pub paths: &'a Option<AHashMap<String, String>>,
+    /// External dependencies
+    pub externals: &'a Externals,
+    /// Sources of the collected external dependencies
+    pub external_srcs: Vec<String>,
     /// Unresolved context
     pub unresolved_ctxt: SyntaxContext,
 }
@@ -36,17 +51,26 @@ impl<'a> ModuleCollector<'a> {
     pub fn new(
         unresolved_ctxt: SyntaxContext,
         runtime: bool,
+        kind: ModuleKind,
         ctx_ident: &'a Ident,
+        idents: &'a Idents,
         paths: &'a Option<AHashMap<String, String>>,
+        externals: &'a Externals,
     ) -> Self {
         Self {
             unresolved_ctxt,
             runtime,
+            kind,
+            cjs_exports: false,
+            in_this_scope: false,
             ctx_ident,
+            idents,
             paths,
+            externals,
+            external_srcs: Vec::new(),
             deps: Vec::new(),
             exps: Vec::new(),
-            exp_bindings: Vec::new(),
+            hoisted_stmts: Vec::new(),
         }
     }
 
@@ -58,12 +82,168 @@ impl<'a> ModuleCollector<'a> {
         mem::take(&mut self.exps)
     }
 
-    pub fn take_bindings(&mut self) -> Vec<ExpBinding> {
-        mem::take(&mut self.exp_bindings)
+    pub fn take_hoisted_stmts(&mut self) -> Vec<Stmt> {
+        mem::take(&mut self.hoisted_stmts)
+    }
+
+    /// Returns `true` if the (collected) source is an external dependency
+    pub fn is_external(&self, src: &str) -> bool {
+        self.external_srcs
+            .iter()
+            .any(|external_src| external_src == src)
+    }
+
+    /// Collects the source of the dependency and returns it.
+    ///
+    /// External sources are kept as they are, and the others are mapped by `paths`.
+    fn collect_src(&mut self, src: String) -> String {
+        if !self.externals.is_external(&src) {
+            return to_mapped_src(&src, self.paths);
+        }
+
+        if !self.is_external(&src) {
+            self.external_srcs.push(src.clone());
+        }
+
+        src
+    }
+
+    /// Visits the top-level statement.
+    fn visit_mut_top_level_stmt(&mut self, stmt: &mut Stmt) {
+        // AMD's define call (CommonJS modules only)
+        //
+        // ```js
+        // // Given code
+        // define(['./foo'], function (foo) { ... });
+        //
+        // // Converted code (CommonJS)
+        // module.exports = function (foo) { ... }(require('./foo')) ?? module.exports;
+        // ```
+        if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
+            if let Expr::Call(call_expr) = &**expr {
+                if !self.kind.is_esm() && is_define_call(self.unresolved_ctxt, call_expr) {
+                    if let Some(cjs_expr) =
+                        define_call_as_cjs_expr(self.unresolved_ctxt, call_expr, self.runtime)
+                    {
+                        **expr = cjs_expr;
+                    }
+                }
+            }
+        }
+
+        stmt.visit_mut_with(self);
+    }
+
+    /// Collects the URL dependency of the module relative URL expression.
+    ///
+    /// Returns `false` if the expression is not a module relative URL.
+    ///
+    /// ```js
+    /// // Given code
+    /// new URL('./foo.png', import.meta.url);
+    ///
+    /// // Transformed code (runtime phase)
+    /// global.__modules.url('./foo.png');
+    /// ```
+    fn collect_url_dep(&mut self, kind: UrlDepKind, expr: &mut Expr) -> bool {
+        let Some(src) = expr
+            .as_new()
+            .and_then(|new_expr| as_url_src(self.unresolved_ctxt, new_expr))
+        else {
+            return false;
+        };
+
+        let src = to_mapped_src(&src, self.paths);
+        self.deps.push(Dep::url(src.clone(), kind));
+
+        if self.runtime {
+            *expr = url_call(Lit::Str(src.into()));
+        }
+
+        true
+    }
+
+    /// Visits the children in a scope that has its own `this`.
+    fn visit_mut_in_this_scope<N: VisitMutWith<Self>>(&mut self, node: &mut N) {
+        let in_this_scope = mem::replace(&mut self.in_this_scope, true);
+        node.visit_mut_children_with(self);
+        self.in_this_scope = in_this_scope;
+    }
+
+    /// Visits the node in a scope that has its own `this`.
+    fn visit_mut_node_in_this_scope<N: VisitMutWith<Self>>(&mut self, node: &mut N) {
+        let in_this_scope = mem::replace(&mut self.in_this_scope, true);
+        node.visit_mut_with(self);
+        self.in_this_scope = in_this_scope;
+    }
+
+    /// Visits the class method.
+    ///
+    /// The decorators are evaluated in the outer scope,
+    /// and the parameters and the body in the method's `this` scope.
+    fn visit_mut_method(&mut self, function: &mut Function) {
+        function.decorators.visit_mut_with(self);
+
+        for param in &mut function.params {
+            param.decorators.visit_mut_with(self);
+            self.visit_mut_node_in_this_scope(&mut param.pat);
+        }
+
+        self.visit_mut_node_in_this_scope(&mut function.body);
+    }
+
+    /// Visits the class member.
+    ///
+    /// Computed keys and decorators are evaluated in the outer scope,
+    /// and the others (eg. method bodies, property values) in the class `this` scope.
+    fn visit_mut_member(&mut self, member: &mut ClassMember) {
+        match member {
+            ClassMember::Constructor(constructor) => {
+                for param in &mut constructor.params {
+                    match param {
+                        ParamOrTsParamProp::Param(param) => {
+                            param.decorators.visit_mut_with(self);
+                            self.visit_mut_node_in_this_scope(&mut param.pat);
+                        }
+                        ParamOrTsParamProp::TsParamProp(param_prop) => {
+                            param_prop.decorators.visit_mut_with(self);
+                            self.visit_mut_node_in_this_scope(&mut param_prop.param);
+                        }
+                    }
+                }
+
+                self.visit_mut_node_in_this_scope(&mut constructor.body);
+            }
+            ClassMember::Method(method) => {
+                method.key.visit_mut_with(self);
+                self.visit_mut_method(&mut method.function);
+            }
+            ClassMember::PrivateMethod(private_method) => {
+                self.visit_mut_method(&mut private_method.function);
+            }
+            ClassMember::ClassProp(class_prop) => {
+                class_prop.key.visit_mut_with(self);
+                class_prop.decorators.visit_mut_with(self);
+                self.visit_mut_node_in_this_scope(&mut class_prop.value);
+            }
+            ClassMember::PrivateProp(private_prop) => {
+                private_prop.decorators.visit_mut_with(self);
+                self.visit_mut_node_in_this_scope(&mut private_prop.value);
+            }
+            ClassMember::AutoAccessor(auto_accessor) => {
+                auto_accessor.key.visit_mut_with(self);
+                auto_accessor.decorators.visit_mut_with(self);
+                self.visit_mut_node_in_this_scope(&mut auto_accessor.value);
+            }
+            ClassMember::StaticBlock(static_block) => {
+                self.visit_mut_node_in_this_scope(static_block);
+            }
+            ClassMember::TsIndexSignature(_) | ClassMember::Empty(_) => {}
+        }
     }
 }
 
-impl<'a> VisitMut for ModuleCollector<'a> {
+impl VisitMut for ModuleCollector<'_> {
     noop_visit_mut_type!();
 
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
@@ -71,8 +251,8 @@ impl<'a> VisitMut for ModuleCollector<'a> {
             match item {
                 // Statements
                 //
-                // It can include CommonJS's require call / module exports or ESModule's dynamic imports.
-                ModuleItem::Stmt(_) => item.visit_mut_children_with(self),
+                // It can include CommonJS's require call / module exports, AMD's define call or ESModule's dynamic imports.
+                ModuleItem::Stmt(stmt) => self.visit_mut_top_level_stmt(stmt),
                 // Imports & Exports (ESModule)
                 ModuleItem::ModuleDecl(module_decl) => {
                     match module_decl {
@@ -85,7 +265,9 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                         // import * as foo from './foo';
                         // ```
                         ModuleDecl::Import(import_decl) => {
-                            if let Some(dep) = import_as_dep(import_decl, self.paths) {
+                            if let Some(dep) =
+                                import_as_dep(import_decl, |src| self.collect_src(src))
+                            {
                                 self.deps.push(dep);
                             }
                         }
@@ -98,12 +280,9 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                         // ```
                         ModuleDecl::ExportDecl(export_decl) => {
                             export_decl.visit_mut_children_with(self);
-                            if let Some((exp, decl_stmt, exp_binding)) =
-                                export_decl_as_exp(export_decl)
-                            {
+                            if let Some((exp, decl_stmt)) = export_decl_as_exp(export_decl) {
                                 *item = decl_stmt.into();
                                 self.exps.push(exp);
-                                self.exp_bindings.push(exp_binding);
                             }
                         }
                         // Default export statements with declarations.
@@ -114,12 +293,12 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                         // ```
                         ModuleDecl::ExportDefaultDecl(export_default_decl) => {
                             export_default_decl.visit_mut_children_with(self);
-                            if let Some((exp, decl, exp_binding)) =
-                                export_default_decl_as_exp(export_default_decl)
+                            if let Some((exp, decl, hoisted_stmt)) =
+                                export_default_decl_as_exp(export_default_decl, self.idents)
                             {
                                 *item = decl.into();
                                 self.exps.push(exp);
-                                self.exp_bindings.push(exp_binding)
+                                self.hoisted_stmts.extend(hoisted_stmt);
                             }
                         }
                         // Default export statements.
@@ -129,11 +308,10 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                         // ```
                         ModuleDecl::ExportDefaultExpr(export_default_expr) => {
                             export_default_expr.visit_mut_children_with(self);
-                            let (exp, stmt, exp_binding) =
-                                export_default_expr_as_exp(export_default_expr);
+                            let (exp, stmt) =
+                                export_default_expr_as_exp(export_default_expr, self.idents);
                             *item = stmt.into();
                             self.exps.push(exp);
-                            self.exp_bindings.push(exp_binding);
                         }
                         // Named export statements.
                         //
@@ -155,15 +333,11 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                                 ..
                             },
                         ) => {
-                            if let Some((exp, exp_bindings)) =
-                                export_named_as_exp(export_named, self.paths)
+                            if let Some(exp) =
+                                export_named_as_exp(export_named, |src| self.collect_src(src))
                             {
-                                match exp {
-                                    Exp::Base(_) => {
-                                        self.exp_bindings.extend(exp_bindings);
-                                        item.take();
-                                    }
-                                    _ => {}
+                                if let Exp::Base(_) = exp {
+                                    item.take();
                                 }
 
                                 self.exps.push(exp);
@@ -180,7 +354,10 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                                 with: None,
                                 ..
                             },
-                        ) => self.exps.push(export_all_as_exp(export_all, self.paths)),
+                        ) => {
+                            let exp = export_all_as_exp(export_all, |src| self.collect_src(src));
+                            self.exps.push(exp);
+                        }
                         _ => {}
                     }
                 }
@@ -201,11 +378,17 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                 match &*call_expr.args[0].expr {
                     // The first argument of the `require` function must be a string type only.
                     Expr::Lit(lit) => {
-                        let src = lit_to_string(lit);
-                        self.deps.push(Dep::runtime());
+                        let src = self.collect_src(lit_to_string(lit));
+                        self.deps.push(Dep::runtime(src.clone()));
 
                         if self.runtime {
-                            *expr = require_call(Lit::Str(to_mapped_src(&src, self.paths).into()));
+                            if !self.is_external(&src) {
+                                *expr = require_call(Lit::Str(src.into()));
+                            } else if let Some(accessor) = &self.externals.accessor {
+                                // External dependencies are kept as they are
+                                // unless the host accessor is provided.
+                                *expr = host_require_call(accessor, Lit::Str(src.into()));
+                            }
                         }
                     }
                     _ => HANDLER.with(|handler| {
@@ -223,16 +406,22 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                     ..
                 },
             ) => {
-                let src = call_expr.args.get(0).expect("invalid dynamic import call");
+                let src = call_expr.args.first().expect("invalid dynamic import call");
 
                 match &*src.expr {
                     // The first argument of the `import` function must be a string type only.
                     Expr::Lit(lit) => {
-                        let src = lit_to_string(lit);
-                        self.deps.push(Dep::runtime());
+                        let src = self.collect_src(lit_to_string(lit));
+                        self.deps.push(Dep::runtime(src.clone()));
 
                         if self.runtime {
-                            *expr = import_call(Lit::Str(to_mapped_src(&src, self.paths).into()));
+                            if !self.is_external(&src) {
+                                *expr = import_call(Lit::Str(src.into()));
+                            } else if let Some(accessor) = &self.externals.accessor {
+                                // External dependencies are kept as they are
+                                // unless the host accessor is provided.
+                                *expr = host_import_call(accessor, Lit::Str(src.into()));
+                            }
                         }
                     }
                     _ => HANDLER.with(|handler| {
@@ -242,88 +431,224 @@ impl<'a> VisitMut for ModuleCollector<'a> {
                     }),
                 }
             }
-            // Case 1. CommonJS's module exports assignment
+            // Web Worker with the module relative URL
             //
             // ```js
-            // exports.foo = ...;
-            // module.exports = ...;
-            // module.exports.foo = ...;
+            // new Worker(new URL('./worker.js', import.meta.url), { type: 'module' });
             // ```
-            Expr::Assign(
-                assign_expr @ AssignExpr {
-                    op: AssignOp::Assign,
-                    ..
-                },
-            ) => match &assign_expr.left {
-                AssignTarget::Simple(SimpleAssignTarget::Member(member_expr)) => {
-                    let module_assign_expr = if is_cjs_exp_member(self.unresolved_ctxt, member_expr)
+            Expr::New(new_expr) if is_worker_new_expr(self.unresolved_ctxt, new_expr) => {
+                let mut args = new_expr.args.iter_mut().flatten();
+
+                if let Some(url_arg) = args.next() {
+                    if url_arg.spread.is_some()
+                        || !self.collect_url_dep(UrlDepKind::Worker, &mut url_arg.expr)
                     {
-                        // `exports.foo = ...;`
-                        Some(assign_cjs_module_expr(
-                            self.ctx_ident,
-                            *assign_expr.right.clone(),
-                            to_cjs_export_name(&member_expr.prop).into(),
-                        ))
-                    } else if is_cjs_mod_member(self.unresolved_ctxt, member_expr) {
-                        // `module.exports = ...;`
-                        Some(assign_cjs_module_expr(
-                            self.ctx_ident,
-                            *assign_expr.right.clone(),
-                            None,
-                        ))
-                    } else if let Some(leading_member) = member_expr.obj.as_member() {
-                        // `module.exports.foo = ...;`
-                        if is_cjs_mod_member(self.unresolved_ctxt, leading_member) {
-                            Some(assign_cjs_module_expr(
-                                self.ctx_ident,
-                                *assign_expr.right.clone(),
-                                to_cjs_export_name(&member_expr.prop).into(),
-                            ))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    };
-
-                    if let Some(module_assign_expr) = module_assign_expr {
-                        // If it is a module exports assignment, replace the right-hand side with the new expression.
-                        assign_expr.right = Box::new(module_assign_expr);
-                    } else {
-                        expr.visit_mut_children_with(self);
+                        url_arg.visit_mut_with(self);
                     }
                 }
-                _ => expr.visit_mut_children_with(self),
-            },
-            // Case 2. CommonJS's module exports as value
+
+                args.for_each(|arg| arg.visit_mut_with(self));
+            }
+            // Asset with the module relative URL
             //
             // ```js
-            // Object.assign(module.exports, ...);
-            // Object.assign(module.exports.foo, ...);
+            // new URL('./foo.png', import.meta.url);
+            // ```
+            Expr::New(new_expr) if as_url_src(self.unresolved_ctxt, new_expr).is_some() => {
+                self.collect_url_dep(UrlDepKind::Asset, expr);
+            }
+            // Case 1. CommonJS's module exports reassignment (CommonJS modules only)
+            //
+            // ```js
+            // module.exports = ...;
+            // module.exports ??= ...;
             // ```
-            Expr::Member(member_expr) if is_cjs_mod_member(self.unresolved_ctxt, member_expr) => {
-                // Replace the member expression with the new expression.
+            Expr::Assign(
+                assign_expr @ AssignExpr {
+                    left: AssignTarget::Simple(SimpleAssignTarget::Member(_)),
+                    ..
+                },
+            ) if !self.kind.is_esm()
+                && assign_expr
+                    .left
+                    .as_simple()
+                    .and_then(|left| left.as_member())
+                    .is_some_and(|member_expr| {
+                        is_cjs_mod_member(self.unresolved_ctxt, member_expr)
+                    }) =>
+            {
+                self.cjs_exports = true;
+                assign_expr.right.visit_mut_with(self);
+
+                // Keeps the context's module exports aliased to the new module exports.
                 //
                 // ```js
                 // // Given code
-                // Object.assign(module.exports, ...);
+                // module.exports = ...;
+                // module.exports ??= ...;
                 //
                 // // Transformed code
-                // Object.assign(ctx_ident.module.exports = module.exports, ...);
+                // module.exports = ctx_ident.module.exports = ...;
+                // ctx_ident.module.exports = module.exports ??= ...;
                 // ```
-                *expr = module_exports_member(self.ctx_ident)
-                    .make_assign_to(AssignOp::Assign, member_expr.clone().into());
+                if assign_expr.op == AssignOp::Assign {
+                    assign_expr.right = Box::new(assign_cjs_module_expr(
+                        self.ctx_ident,
+                        *assign_expr.right.take(),
+                    ));
+                } else {
+                    *expr = assign_cjs_module_expr(self.ctx_ident, expr.take());
+                }
+            }
+            // Case 2. CommonJS's module exports references (CommonJS modules only)
+            //
+            // The context's module exports is aliased to the module exports object,
+            // so every mutation (assignments, compound assignments, `delete`, method calls)
+            // is reflected without rewriting.
+            //
+            // ```js
+            // exports.foo = ...;
+            // exports.count += 1;
+            // delete exports.foo;
+            // module.exports.foo = ...;
+            // Object.assign(module.exports, ...);
+            // ```
+            Expr::Member(member_expr)
+                if !self.kind.is_esm() && is_cjs_mod_member(self.unresolved_ctxt, member_expr) =>
+            {
+                self.cjs_exports = true;
+            }
+            Expr::Ident(ident)
+                if !self.kind.is_esm() && is_cjs_exp_ident(self.unresolved_ctxt, ident) =>
+            {
+                self.cjs_exports = true;
+            }
+            // UMD's AMD environment check (CommonJS modules only)
+            //
+            // The AMD branch of the UMD wrappers must not be taken,
+            // even if the global `define` function exists at runtime.
+            //
+            // ```js
+            // // Given code
+            // typeof define === 'function' && define.amd;
+            //
+            // // Transformed code
+            // "undefined" === 'function' && define.amd;
+            // ```
+            Expr::Unary(unary_expr)
+                if !self.kind.is_esm() && is_typeof_define(self.unresolved_ctxt, unary_expr) =>
+            {
+                *expr = str_lit("undefined").into();
+            }
+            // `import.meta.resolve` call with the static specifier (runtime phase only)
+            //
+            // The specifier is mapped by `paths` so that the registry can resolve it.
+            //
+            // ```js
+            // // Given code
+            // import.meta.resolve('./foo');
+            //
+            // // Transformed code
+            // ctx_ident.meta.resolve('1001');
+            // ```
+            Expr::Call(call_expr)
+                if self.runtime && as_import_meta_resolve_specifier(call_expr).is_some() =>
+            {
+                let specifier = as_import_meta_resolve_specifier(call_expr).unwrap();
+
+                call_expr.args[0].expr =
+                    Box::new(str_lit(&to_mapped_src(&specifier, self.paths)).into());
+                call_expr.visit_mut_children_with(self);
+            }
+            // `import.meta` (runtime phase only)
+            //
+            // The registry evaluates the transformed code as a classic script,
+            // where `import.meta` is a syntax error.
+            //
+            // ```js
+            // // Given code
+            // import.meta.url;
+            //
+            // // Transformed code
+            // ctx_ident.meta.url;
+            // ```
+            Expr::MetaProp(_) if self.runtime && is_import_meta(expr) => {
+                *expr = module_meta_member(self.ctx_ident).into();
             }
+            // Top-level `this`
+            //
+            // The registry may evaluate the transformed code in a context
+            // where `this` is the global object, so it is replaced with the module's `this`.
+            //
+            // ```js
+            // // ESModule
+            // this; // undefined
+            //
+            // // CommonJS
+            // this; // ctx_ident.module.exports
+            //
+            // // Script (kept as it is)
+            // this;
+            // ```
+            Expr::This(_) if self.runtime && !self.in_this_scope => match self.kind {
+                ModuleKind::Esm | ModuleKind::Mixed => *expr = *Expr::undefined(DUMMY_SP),
+                ModuleKind::Cjs => *expr = module_exports_member(self.ctx_ident).into(),
+                ModuleKind::Script => {}
+            },
             _ => expr.visit_mut_children_with(self),
         }
     }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        for stmt in script.body.iter_mut() {
+            self.visit_mut_top_level_stmt(stmt);
+        }
+    }
+
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        self.visit_mut_in_this_scope(function);
+    }
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        // The decorators and the super class expression are evaluated in the outer scope.
+        class.decorators.visit_mut_with(self);
+        class.super_class.visit_mut_with(self);
+
+        for member in &mut class.body {
+            self.visit_mut_member(member);
+        }
+    }
+
+    fn visit_mut_getter_prop(&mut self, getter_prop: &mut GetterProp) {
+        // The computed key is evaluated in the outer scope.
+        getter_prop.key.visit_mut_with(self);
+        self.visit_mut_node_in_this_scope(&mut getter_prop.body);
+    }
+
+    fn visit_mut_setter_prop(&mut self, setter_prop: &mut SetterProp) {
+        setter_prop.key.visit_mut_with(self);
+        self.visit_mut_node_in_this_scope(&mut setter_prop.this_param);
+        self.visit_mut_node_in_this_scope(&mut setter_prop.param);
+        self.visit_mut_node_in_this_scope(&mut setter_prop.body);
+    }
 }
 
 pub fn create_collector<'a>(
     unresolved_ctxt: SyntaxContext,
     runtime: bool,
+    kind: ModuleKind,
     ctx_ident: &'a Ident,
+    idents: &'a Idents,
     paths: &'a Option<AHashMap<String, String>>,
+    externals: &'a Externals,
 ) -> ModuleCollector<'a> {
-    ModuleCollector::new(unresolved_ctxt, runtime, ctx_ident, paths)
+    ModuleCollector::new(
+        unresolved_ctxt,
+        runtime,
+        kind,
+        ctx_ident,
+        idents,
+        paths,
+        externals,
+    )
 }
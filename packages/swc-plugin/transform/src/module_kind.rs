@@ -0,0 +1,145 @@
+use swc_core::{
+    common::SyntaxContext,
+    ecma::{
+        ast::*,
+        visit::{noop_visit_type, Visit, VisitWith},
+    },
+};
+
+use crate::{
+    scope::{ensure_resolved, is_resolved},
+    utils::ast::{
+        is_cjs_exp_ident, is_cjs_mod_member, is_define_call, is_require_call, is_typeof_define,
+    },
+};
+
+/// Module kind of the source.
+///
+/// ```js
+/// // ESModule
+/// import foo from './foo';
+/// export const bar = 1;
+///
+/// // CommonJS
+/// const foo = require('./foo');
+/// module.exports.bar = 1;
+///
+/// // Mixed (treated as ESModule)
+/// import foo from './foo';
+/// module.exports.bar = 1;
+///
+/// // Script (neither of them)
+/// console.log(this);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleKind {
+    /// ESModule (`import` / `export` statements)
+    Esm,
+    /// CommonJS (`module.exports` / `exports` references, `require` or AMD `define` calls)
+    Cjs,
+    /// Both ESModule and CommonJS
+    ///
+    /// Transformed as ESModule (CommonJS exports are not registered).
+    Mixed,
+    /// Plain script without any module syntax
+    ///
+    /// Registered without exports, and the top-level `this` is kept as it is.
+    Script,
+}
+
+impl ModuleKind {
+    /// Returns `true` if the module is transformed as ESModule
+    pub fn is_esm(&self) -> bool {
+        matches!(self, ModuleKind::Esm | ModuleKind::Mixed)
+    }
+}
+
+/// Detects the module kind of the program.
+///
+/// `unresolved_ctxt` is the unresolved context of the `resolver` pass.
+/// If the program is not resolved yet, it is resolved internally.
+pub fn detect_module_kind(program: &Program, unresolved_ctxt: SyntaxContext) -> ModuleKind {
+    if is_resolved(program) {
+        return detect_resolved_module_kind(program, unresolved_ctxt);
+    }
+
+    let mut program = program.clone();
+    let unresolved_ctxt = ensure_resolved(&mut program, unresolved_ctxt);
+
+    detect_resolved_module_kind(&program, unresolved_ctxt)
+}
+
+/// Detects the module kind of the resolved program.
+pub(crate) fn detect_resolved_module_kind(
+    program: &Program,
+    unresolved_ctxt: SyntaxContext,
+) -> ModuleKind {
+    let mut detector = ModuleKindDetector {
+        unresolved_ctxt,
+        esm: false,
+        cjs: false,
+        cjs_calls: false,
+    };
+
+    program.visit_with(&mut detector);
+
+    match (detector.esm, detector.cjs, detector.cjs_calls) {
+        (true, false, _) => ModuleKind::Esm,
+        (true, true, _) => ModuleKind::Mixed,
+        (false, true, _) | (false, false, true) => ModuleKind::Cjs,
+        (false, false, false) => ModuleKind::Script,
+    }
+}
+
+struct ModuleKindDetector {
+    unresolved_ctxt: SyntaxContext,
+    /// Whether the program has `import` / `export` statements
+    esm: bool,
+    /// Whether the program references `module.exports` / `exports`
+    cjs: bool,
+    /// Whether the program calls `require` / AMD `define` (or checks `typeof define`)
+    ///
+    /// Not counted as the CommonJS usage of ESModules (eg. `require` in a bundled ESModule).
+    cjs_calls: bool,
+}
+
+impl Visit for ModuleKindDetector {
+    noop_visit_type!();
+
+    fn visit_module_decl(&mut self, module_decl: &ModuleDecl) {
+        self.esm = true;
+        module_decl.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        if is_require_call(self.unresolved_ctxt, call_expr)
+            || is_define_call(self.unresolved_ctxt, call_expr)
+        {
+            self.cjs_calls = true;
+        }
+
+        call_expr.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+        if is_typeof_define(self.unresolved_ctxt, unary_expr) {
+            self.cjs_calls = true;
+        }
+
+        unary_expr.visit_children_with(self);
+    }
+
+    fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
+        if is_cjs_mod_member(self.unresolved_ctxt, member_expr) {
+            self.cjs = true;
+        }
+
+        member_expr.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if is_cjs_exp_ident(self.unresolved_ctxt, ident) {
+            self.cjs = true;
+        }
+    }
+}
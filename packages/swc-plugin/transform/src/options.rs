@@ -0,0 +1,189 @@
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use swc_core::{common::collections::AHashMap, ecma::ast::Ident};
+
+/// Transform options.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Options {
+    /// Names of the generated identifiers
+    pub identifiers: Identifiers,
+    /// Compact output
+    ///
+    /// Export properties and specifiers are emitted in their shortest form
+    /// (eg. `{ foo, get bar() { ... } }`, `export { foo }`),
+    /// and all exports are registered with a single exports call.
+    pub compact: bool,
+    /// Output format (default: `globalModules`)
+    pub format: OutputFormat,
+    /// External dependencies
+    pub externals: Externals,
+    /// Exports used at runtime (key: module ID)
+    ///
+    /// Only the listed exports of the module are registered with `__context.exports(...)`,
+    /// so the bundler can remove the unused ones. Modules that are not listed register all exports.
+    /// (see `ModuleGraph::used_exports`)
+    pub used_exports: AHashMap<String, Vec<String>>,
+    /// Placement of the register call in the bundle phase (default: `afterImports`)
+    pub register_placement: RegisterPlacement,
+}
+
+impl Options {
+    /// Returns an error message if any of the generated names is invalid
+    /// (see `Identifiers::verify` and `Externals::verify`).
+    pub fn verify(&self) -> Result<(), String> {
+        self.identifiers.verify()?;
+        self.externals.verify()
+    }
+}
+
+/// Placement of the register call relative to the import statements.
+///
+/// The exports call is always placed right after the register call (and the hoisted statements),
+/// and the namespace exports calls and the module statements come after the imports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RegisterPlacement {
+    /// Registers the module after the import statements
+    ///
+    /// ```js
+    /// import { foo } from './foo';
+    /// const __context = global.__modules.register(id);
+    /// __context.exports(...);
+    /// ```
+    #[default]
+    AfterImports,
+    /// Registers the module before the import statements,
+    /// so the exports are registered before the dependencies are evaluated
+    /// when the bundler keeps the statement order (eg. imports converted into `require` calls).
+    ///
+    /// ```js
+    /// const __context = global.__modules.register(id);
+    /// __context.exports(...);
+    /// import { foo } from './foo';
+    /// ```
+    BeforeImports,
+}
+
+/// Output format of the transformed module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputFormat {
+    /// Registers the module to the global module registry
+    ///
+    /// ```js
+    /// const __context = global.__modules.register(id);
+    /// ```
+    #[default]
+    GlobalModules,
+    /// Registers the module with SystemJS (ESModule only)
+    ///
+    /// ```js
+    /// System.register(id, [...], function (_export, _context) { ... });
+    /// ```
+    System,
+}
+
+/// Names of the generated identifiers.
+///
+/// If a name collides with an identifier of the source,
+/// it is prefixed with `_` until it no longer collides (eg. `__context` -> `___context`).
+///
+/// Unknown names are rejected.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+pub struct Identifiers {
+    /// Module context identifier (default: `__context`)
+    pub context: String,
+    /// Anonymous default export identifier (default: `__default`)
+    pub default: String,
+    /// Re-exported module identifier (default: `__mod`)
+    pub module: String,
+}
+
+impl Default for Identifiers {
+    fn default() -> Self {
+        Self {
+            context: String::from("__context"),
+            default: String::from("__default"),
+            module: String::from("__mod"),
+        }
+    }
+}
+
+impl Identifiers {
+    /// Returns an error message if any of the names is not a valid identifier
+    /// (eg. `foo-bar`, reserved words).
+    pub fn verify(&self) -> Result<(), String> {
+        [
+            ("context", &self.context),
+            ("default", &self.default),
+            ("module", &self.module),
+        ]
+        .iter()
+        .try_for_each(|(key, name)| {
+            Ident::verify_symbol(name)
+                .map_err(|_| format!("invalid identifier name of `{}`: '{}'", key, name))
+        })
+    }
+}
+
+/// External dependencies.
+///
+/// External sources are not mapped by `paths` and not referenced through the global module registry.
+/// In the runtime phase, they are kept as native `import` / `require` statements,
+/// or referenced through the host accessor if it is provided.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Externals {
+    /// Exact sources (eg. `node:fs`)
+    pub names: Vec<String>,
+    /// Source patterns (regular expressions, eg. `^node:`)
+    #[serde(deserialize_with = "deserialize_patterns")]
+    pub patterns: Vec<Regex>,
+    /// Host accessor of the external modules (eg. `global.__host`)
+    ///
+    /// The accessor must be a member path of identifiers (eg. `host`, `global.__host`),
+    /// and provide the `require(src)` and `import(src)` methods (same as the global module registry).
+    pub accessor: Option<String>,
+}
+
+impl Externals {
+    /// Returns an error message if the accessor is not a valid member path
+    /// (eg. empty, `a..b`, `1x`).
+    pub fn verify(&self) -> Result<(), String> {
+        let Some(accessor) = &self.accessor else {
+            return Ok(());
+        };
+        let mut segments = accessor.split('.');
+        let is_valid_root = segments
+            .next()
+            .is_some_and(|root| Ident::verify_symbol(root).is_ok());
+        let is_valid_props = segments.all(|prop| {
+            let mut chars = prop.chars();
+
+            chars.next().is_some_and(Ident::is_valid_start) && chars.all(Ident::is_valid_continue)
+        });
+
+        if is_valid_root && is_valid_props {
+            Ok(())
+        } else {
+            Err(format!("invalid accessor of `externals`: '{}'", accessor))
+        }
+    }
+
+    /// Returns `true` if the source is an external dependency
+    pub fn is_external(&self, src: &str) -> bool {
+        self.names.iter().any(|name| name == src)
+            || self.patterns.iter().any(|pattern| pattern.is_match(src))
+    }
+}
+
+fn deserialize_patterns<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Regex>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|pattern| Regex::new(pattern).map_err(serde::de::Error::custom))
+        .collect()
+}
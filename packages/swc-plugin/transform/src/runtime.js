@@ -0,0 +1,175 @@
+// Generated from `@global-modules/runtime` by `build/runtime.mjs`. Do not edit.
+(() => {
+  // src/exports.ts
+  var __ref = {};
+  function createExports() {
+    return Object.create(__ref);
+  }
+  function isExports(object) {
+    return Object.getPrototypeOf(object) === __ref;
+  }
+
+  // src/interop-default-export.ts
+  function interopDefaultExport(module) {
+    if (typeof module.exports.default === "undefined") {
+      return typeof module.exports === "object" ? Object.assign(module.exports, { default: module.exports }) : { default: module.exports };
+    }
+    return module.exports;
+  }
+
+  // src/utils.ts
+  var hasOwnProp = Object.prototype.hasOwnProperty;
+  var defProp = Object.defineProperty;
+  var origins = /* @__PURE__ */ new WeakMap();
+  var resolveOrigin = (source, key) => origins.get(source)?.[key] ?? source;
+  var defineGetter = (destination, source, key) => {
+    let destinationOrigins = origins.get(destination);
+    if (destinationOrigins == null) {
+      destinationOrigins = /* @__PURE__ */ Object.create(null);
+      origins.set(destination, destinationOrigins);
+    }
+    destinationOrigins[key] = resolveOrigin(source, key);
+    defProp(destination, key, {
+      enumerable: true,
+      get: () => source[key]
+    });
+  };
+  var copyProps = (destination, source, except) => {
+    for (const key in source) {
+      if (key !== except && hasOwnProp.call(source, key) && !hasOwnProp.call(destination, key)) {
+        defineGetter(destination, source, key);
+      }
+    }
+    return destination;
+  };
+
+  // src/create-global-module.ts
+  function createGlobalModule() {
+    const moduleRegistry = /* @__PURE__ */ new Map();
+    const urlRegistry = /* @__PURE__ */ new Map();
+    function __exports(exports, definitions, sources) {
+      copyProps(exports, definitions());
+      if (sources.length) {
+        copyProps(exports, toNamespaceExports(...sources));
+      }
+    }
+    function require2(id) {
+      const module = getModule(id).context.module;
+      return module.exports.__esModule || isExports(module.exports) ? module.exports : interopDefaultExport(module);
+    }
+    function getModule(id) {
+      const module = moduleRegistry.get(id);
+      if (module == null) {
+        throw new Error(`module not found: '${id}'`);
+      }
+      return module;
+    }
+    function registerUrl(id, url2) {
+      urlRegistry.set(id, String(url2));
+    }
+    function url(id) {
+      const registeredUrl = urlRegistry.get(id);
+      if (registeredUrl == null) {
+        throw new Error(`url not found: '${id}'`);
+      }
+      return new URL(registeredUrl);
+    }
+    function toNamespaceExports(...sources) {
+      const nsExports = createExports();
+      const sourceOf = /* @__PURE__ */ new Map();
+      const ambiguousKeys = /* @__PURE__ */ new Set(["default"]);
+      for (const source of sources) {
+        for (const key in source) {
+          if (!hasOwnProp.call(source, key)) {
+            continue;
+          }
+          const prevSource = sourceOf.get(key);
+          if (prevSource == null) {
+            sourceOf.set(key, source);
+          } else if (resolveOrigin(prevSource, key) !== resolveOrigin(source, key)) {
+            ambiguousKeys.add(key);
+          }
+        }
+      }
+      for (const [key, source] of sourceOf) {
+        if (!ambiguousKeys.has(key)) {
+          defineGetter(nsExports, source, key);
+        }
+      }
+      return nsExports;
+    }
+    function createMeta(id) {
+      return {
+        id,
+        get url() {
+          return urlRegistry.get(id) ?? id;
+        },
+        resolve: (specifier) => urlRegistry.get(specifier) ?? specifier
+      };
+    }
+    function createContext(id) {
+      const module = { exports: createExports() };
+      return {
+        // `import.meta`
+        meta: createMeta(id),
+        // Exports object
+        module,
+        // Exports function
+        //
+        // `context.exports(...);`
+        // `context.exports(..., ...sources);`
+        // `context.exports.ns(...);`
+        exports: Object.assign(
+          (definitions, ...sources) => {
+            __exports(module.exports, definitions, sources);
+          },
+          { ns: toNamespaceExports }
+        )
+      };
+    }
+    function register(id) {
+      const module = moduleRegistry.get(id) ?? {};
+      module.id = id;
+      module.context = createContext(id);
+      moduleRegistry.set(id, module);
+      return module.context;
+    }
+    function clear() {
+      moduleRegistry.clear();
+      urlRegistry.clear();
+    }
+    function getRegistry() {
+      return moduleRegistry;
+    }
+    return {
+      register,
+      require: require2,
+      import: (id) => Promise.resolve(require2(id)),
+      registerUrl,
+      url,
+      getRegistry,
+      getModule,
+      clear
+    };
+  }
+
+  // src/get-global-context.ts
+  function getGlobalContext() {
+    return typeof globalThis !== "undefined" ? globalThis : typeof global !== "undefined" ? global : typeof window !== "undefined" ? window : this;
+  }
+
+  // src/index.ts
+  var GLOBAL_MODULE_PROPERTY = "__modules";
+  var globalContext = getGlobalContext();
+  if (GLOBAL_MODULE_PROPERTY in globalContext) {
+    throw new Error(
+      `'${GLOBAL_MODULE_PROPERTY}' property is already defined in the global context.`
+    );
+  }
+  if (typeof global === "undefined") {
+    globalContext.global = globalContext;
+  }
+  Object.defineProperty(globalContext, GLOBAL_MODULE_PROPERTY, {
+    value: createGlobalModule()
+  });
+})();
@@ -0,0 +1,57 @@
+use swc_core::{
+    common::{Mark, SyntaxContext},
+    ecma::{
+        ast::*,
+        transforms::base::resolver,
+        visit::{noop_visit_type, Visit, VisitMutWith, VisitWith},
+    },
+};
+
+/// Ensures the scope of the identifiers is analyzed.
+///
+/// The CommonJS's `module`, `exports` and `require` are detected by the unresolved context,
+/// which is valid only when the `resolver` pass has been applied with the same mark.
+/// If the program has not been resolved yet (every identifier has an empty context),
+/// applies the `resolver` pass and returns its unresolved context instead.
+///
+/// ```js
+/// require('./foo'); // Unresolved (global) `require`
+///
+/// function foo(require) {
+///   require('./bar'); // Local `require`
+/// }
+/// ```
+pub fn ensure_resolved(program: &mut Program, unresolved_ctxt: SyntaxContext) -> SyntaxContext {
+    if is_resolved(program) {
+        return unresolved_ctxt;
+    }
+
+    let unresolved_mark = Mark::new();
+
+    program.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+
+    SyntaxContext::empty().apply_mark(unresolved_mark)
+}
+
+/// Returns `true` if any identifier of the program has a syntax context.
+pub(crate) fn is_resolved(program: &Program) -> bool {
+    let mut checker = ResolvedChecker::default();
+
+    program.visit_with(&mut checker);
+    checker.resolved
+}
+
+#[derive(Default)]
+struct ResolvedChecker {
+    resolved: bool,
+}
+
+impl Visit for ResolvedChecker {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if ident.ctxt != SyntaxContext::empty() {
+            self.resolved = true;
+        }
+    }
+}
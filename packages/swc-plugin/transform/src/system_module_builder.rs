@@ -0,0 +1,873 @@
+use crate::{
+    idents::Idents,
+    models::{Dep, DepMember, Exp},
+    module_collector::ModuleCollector,
+    utils::{ast::*, helpers::to_mapped_src, presets::*},
+};
+use swc_core::{
+    atoms::Atom,
+    common::{collections::AHashMap, util::take::Take, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{contains_top_level_await, find_pat_ids, quote_ident, ExprFactory},
+        visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
+    },
+};
+
+/// Builds the SystemJS module (`System.register`) from the collected dependencies and exports.
+///
+/// ```js
+/// System.register(id, ['./foo', './bar'], function (_export, _context) {
+///   var foo, baz;
+///   function qux() {}
+///   _export("qux", qux);
+///   return {
+///     setters: [
+///       function (__mod) { foo = __mod.default; },
+///       function (__mod) { _export("bar", __mod.bar); },
+///     ],
+///     execute: function () {
+///       // Module statements
+///       _export("baz", baz = foo);
+///     },
+///   };
+/// });
+/// ```
+pub struct SystemModuleBuilder<'a> {
+    /// Paths
+    paths: &'a Option<AHashMap<String, String>>,
+    /// SystemJS export function identifier (`_export`)
+    export_ident: Ident,
+    /// SystemJS context identifier (`_context`)
+    context_ident: Ident,
+    /// Parameter identifier of the setter functions (`__mod`)
+    mod_ident: Ident,
+    /// Key identifier of the re-export all statements (`__key`)
+    key_ident: Ident,
+    /// Temporary identifier of the postfix updates (`__tmp`)
+    temp_ident: Ident,
+    /// Dependency sources (the order of the setters)
+    srcs: Vec<String>,
+    /// Setter statements of each dependency
+    setter_stmts: Vec<Vec<Stmt>>,
+    /// Import bindings that are assigned by the setters
+    import_bindings: Vec<Ident>,
+    /// Export names of the local bindings
+    exp_names: AHashMap<Id, Vec<Atom>>,
+    /// Explicitly exported names
+    ///
+    /// Excluded from the re-export all statements along with the `default`.
+    explicit_names: Vec<Atom>,
+    /// Dependency indexes of the re-export all statements
+    ns_deps: Vec<usize>,
+    /// Statements to run before the module body
+    hoisted_stmts: Vec<Stmt>,
+}
+
+impl<'a> SystemModuleBuilder<'a> {
+    pub fn new(idents: &'a Idents, paths: &'a Option<AHashMap<String, String>>) -> Self {
+        Self {
+            paths,
+            export_ident: idents.system_export_ident(),
+            context_ident: idents.system_context_ident(),
+            mod_ident: idents.mod_ident(),
+            key_ident: idents.system_key_ident(),
+            temp_ident: idents.system_temp_ident(),
+            srcs: Vec::new(),
+            setter_stmts: Vec::new(),
+            import_bindings: Vec::new(),
+            exp_names: AHashMap::default(),
+            explicit_names: Vec::new(),
+            ns_deps: Vec::new(),
+            hoisted_stmts: Vec::new(),
+        }
+    }
+
+    /// Collects the setters from the collected dependencies and exports
+    pub fn collect(&mut self, collector: &mut ModuleCollector) {
+        let deps = collector.take_deps();
+        let exps = collector.take_exps();
+
+        // Sources of the import statements come first, followed by the re-exports.
+        deps.iter().for_each(|dep| {
+            if let Dep::Base(base_dep) = dep {
+                self.dep_index(base_dep.src.clone());
+            }
+        });
+
+        // Exports are collected first because the imported bindings can be exported as well.
+        //
+        // ```js
+        // import { foo } from './foo';
+        // export { foo };
+        // ```
+        self.collect_exps(exps);
+        self.collect_deps(deps);
+        self.hoisted_stmts = collector.take_hoisted_stmts();
+    }
+
+    /// Returns the dependency index of the source (registered if not exists)
+    fn dep_index(&mut self, src: String) -> usize {
+        match self.srcs.iter().position(|dep_src| *dep_src == src) {
+            Some(index) => index,
+            None => {
+                self.srcs.push(src);
+                self.setter_stmts.push(Vec::new());
+                self.srcs.len() - 1
+            }
+        }
+    }
+
+    /// Collects the setter statements from the collected dependencies
+    ///
+    /// ```js
+    /// function (__mod) {
+    ///   foo = __mod.default;
+    ///   bar = __mod.bar;
+    ///   ns = __mod;
+    /// }
+    /// ```
+    fn collect_deps(&mut self, deps: Vec<Dep>) {
+        for dep in deps {
+            // Runtime dependencies are loaded by `_context.import(...)`
+            let Dep::Base(base_dep) = dep else {
+                continue;
+            };
+
+            let index = self.dep_index(base_dep.src);
+
+            for DepMember { ident, name } in base_dep.members {
+                let value = match name {
+                    Some(name) => member_by_name(self.mod_ident.clone(), &name),
+                    None => self.mod_ident.clone().into(),
+                };
+                let assign_expr = value.make_assign_to(op!("="), ident.clone().into());
+                let setter_expr = self.wrap_exports(&ident.to_id(), assign_expr);
+
+                self.setter_stmts[index].push(setter_expr.into_stmt());
+                self.import_bindings.push(ident);
+            }
+        }
+    }
+
+    /// Collects the export names and the re-export setter statements from the collected exports
+    ///
+    /// ```js
+    /// function (__mod) {
+    ///   _export("foo", __mod.foo);  // export { foo } from './foo';
+    ///   _export("bar", __mod);      // export * as bar from './foo';
+    /// }
+    /// ```
+    fn collect_exps(&mut self, exps: Vec<Exp>) {
+        for exp in exps {
+            match exp {
+                Exp::Base(base_exp) => base_exp.members.into_iter().for_each(|member| {
+                    let name: Atom = member.name.into();
+
+                    self.exp_names
+                        .entry(member.ident.to_id())
+                        .or_default()
+                        .push(name.clone());
+                    self.explicit_names.push(name);
+                }),
+                Exp::ReExportNamed(re_export_named) => {
+                    let index = self.dep_index(re_export_named.src);
+
+                    for member in re_export_named.members {
+                        let value = member_by_name(self.mod_ident.clone(), &member.orig);
+                        let export_call =
+                            system_export_call(&self.export_ident, &member.name, value);
+
+                        self.setter_stmts[index].push(export_call.into_stmt());
+                        self.explicit_names.push(member.name.into());
+                    }
+                }
+                Exp::ReExportAll(re_export_all) => {
+                    let index = self.dep_index(re_export_all.src);
+
+                    match re_export_all.alias {
+                        Some(alias) => {
+                            let export_call = system_export_call(
+                                &self.export_ident,
+                                &alias,
+                                self.mod_ident.clone().into(),
+                            );
+
+                            self.setter_stmts[index].push(export_call.into_stmt());
+                            self.explicit_names.push(alias.into());
+                        }
+                        // Resolved after all explicit names are collected
+                        None => self.ns_deps.push(index),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Wraps the expression with the export calls of the binding (if exported)
+    ///
+    /// ```js
+    /// // Code
+    /// _export("bar", _export("foo", <expr>));
+    /// ```
+    fn wrap_exports(&self, id: &Id, expr: Expr) -> Expr {
+        to_exports_expr(&self.export_ident, &self.exp_names, id, expr)
+    }
+
+    /// Returns the re-export all statement of the setter
+    ///
+    /// The `default` and the explicitly exported names are excluded.
+    ///
+    /// ```js
+    /// for (var __key in __mod) {
+    ///   if (["default", "foo"].indexOf(__key) < 0) _export(__key, __mod[__key]);
+    /// }
+    /// ```
+    fn ns_export_stmt(&self) -> Stmt {
+        let key_ident = self.key_ident.clone();
+        let mut names = vec![Atom::from("default")];
+
+        self.explicit_names.iter().for_each(|name| {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        });
+
+        let excluded_names = ArrayLit {
+            elems: names
+                .iter()
+                .map(|name| Some(str_lit(name).as_arg()))
+                .collect(),
+            span: DUMMY_SP,
+        };
+        let test = excluded_names
+            .make_member(quote_ident!("indexOf"))
+            .as_call(DUMMY_SP, vec![key_ident.clone().as_arg()])
+            .make_bin(op!("<"), Expr::from(0.0));
+        let value = self
+            .mod_ident
+            .clone()
+            .computed_member(Expr::from(key_ident.clone()));
+        let export_call = self
+            .export_ident
+            .clone()
+            .as_call(DUMMY_SP, vec![key_ident.clone().as_arg(), value.as_arg()]);
+
+        ForInStmt {
+            left: ForHead::VarDecl(Box::new(VarDecl {
+                kind: VarDeclKind::Var,
+                decls: vec![var_declarator(key_ident.into(), None)],
+                ..Default::default()
+            })),
+            right: Box::new(self.mod_ident.clone().into()),
+            body: Box::new(
+                IfStmt {
+                    test: Box::new(test),
+                    cons: Box::new(export_call.into_stmt()),
+                    alt: None,
+                    span: DUMMY_SP,
+                }
+                .into(),
+            ),
+            span: DUMMY_SP,
+        }
+        .into()
+    }
+
+    /// Splits the module statements into the declare function and the `execute` function
+    ///
+    /// Module scope bindings are hoisted to the declare function (same as SystemJS),
+    /// so the setters and the circular importers can access them before `execute` runs.
+    ///
+    /// - Declare: hoisted variables, function declarations and their export calls
+    /// - Execute: hoisted statements and module statements
+    ///   (declarations are replaced with assignments, and exported bindings are updated on every assignment)
+    fn split_stmts(&self, orig_stmts: Vec<Stmt>) -> SplitStmts {
+        let mut hoister = VarHoister::default();
+        let mut tracker = ExportsTracker {
+            export_ident: &self.export_ident,
+            context_ident: &self.context_ident,
+            temp_ident: &self.temp_ident,
+            exp_names: &self.exp_names,
+            paths: self.paths,
+            uses_temp: false,
+        };
+        let mut declare_stmts = Vec::new();
+        let mut fn_exports = Vec::new();
+        let mut execute_stmts = self.hoisted_stmts.clone();
+
+        for stmt in orig_stmts {
+            let mut stmt = match stmt {
+                Stmt::Empty(_) => continue,
+                // Function declarations are hoisted, so they are exported before the module body.
+                Stmt::Decl(Decl::Fn(mut fn_decl)) => {
+                    let ident = fn_decl.ident.clone();
+
+                    fn_decl.visit_mut_with(&mut tracker);
+                    declare_stmts.push(fn_decl.into());
+
+                    if self.exp_names.contains_key(&ident.to_id()) {
+                        fn_exports
+                            .push(self.wrap_exports(&ident.to_id(), ident.into()).into_stmt());
+                    }
+                    continue;
+                }
+                Stmt::Decl(decl) => {
+                    // Uninitialized bindings are exported with their initial value (`undefined`).
+                    if let Decl::Var(var_decl) = &decl {
+                        execute_stmts.extend(
+                            var_decl
+                                .decls
+                                .iter()
+                                .filter(|decl| decl.init.is_none())
+                                .flat_map(|decl| find_pat_ids::<_, Ident>(&decl.name))
+                                .filter(|ident| self.exp_names.contains_key(&ident.to_id()))
+                                .map(|ident| {
+                                    self.wrap_exports(&ident.to_id(), ident.into()).into_stmt()
+                                }),
+                        );
+                    }
+
+                    match hoister.hoist_decl(decl) {
+                        Some(stmt) => stmt,
+                        None => continue,
+                    }
+                }
+                mut stmt => {
+                    stmt.visit_mut_with(&mut hoister);
+                    stmt
+                }
+            };
+
+            stmt.visit_mut_with(&mut tracker);
+            execute_stmts.push(stmt);
+        }
+
+        let mut vars = hoister.idents;
+
+        if tracker.uses_temp {
+            vars.push(self.temp_ident.clone());
+        }
+
+        declare_stmts.extend(fn_exports);
+
+        SplitStmts {
+            vars,
+            declare_stmts,
+            execute_stmts,
+        }
+    }
+
+    /// Returns the `System.register` call statement
+    fn build(mut self, id: &str, orig_stmts: Vec<Stmt>) -> Stmt {
+        let is_async = contains_top_level_await(&orig_stmts);
+        let ns_export_stmt = self.ns_export_stmt();
+
+        for index in std::mem::take(&mut self.ns_deps) {
+            self.setter_stmts[index].push(ns_export_stmt.clone());
+        }
+
+        let SplitStmts {
+            vars,
+            declare_stmts: hoisted_decls,
+            execute_stmts,
+        } = self.split_stmts(orig_stmts);
+        let setters = std::mem::take(&mut self.setter_stmts)
+            .into_iter()
+            .map(|stmts| Some(to_fn_expr(vec![self.mod_ident.clone()], stmts, false).as_arg()))
+            .collect();
+
+        let declare_obj = obj_lit_expr(vec![
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: compact_prop_name("setters".into()),
+                value: Box::new(
+                    ArrayLit {
+                        elems: setters,
+                        span: DUMMY_SP,
+                    }
+                    .into(),
+                ),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: compact_prop_name("execute".into()),
+                value: Box::new(to_fn_expr(Vec::new(), execute_stmts, is_async)),
+            }))),
+        ]);
+
+        let mut declare_stmts = Vec::with_capacity(hoisted_decls.len() + 2);
+        let vars = self
+            .import_bindings
+            .drain(..)
+            .chain(vars)
+            .collect::<Vec<Ident>>();
+
+        if !vars.is_empty() {
+            declare_stmts.push(
+                VarDecl {
+                    kind: VarDeclKind::Var,
+                    decls: vars
+                        .into_iter()
+                        .map(|ident| var_declarator(ident.into(), None))
+                        .collect(),
+                    ..Default::default()
+                }
+                .into(),
+            );
+        }
+
+        declare_stmts.extend(hoisted_decls);
+
+        declare_stmts.push(
+            ReturnStmt {
+                arg: Some(Box::new(declare_obj)),
+                span: DUMMY_SP,
+            }
+            .into(),
+        );
+
+        let declare = to_fn_expr(
+            vec![self.export_ident.clone(), self.context_ident.clone()],
+            declare_stmts,
+            false,
+        );
+
+        system_register_call(id, std::mem::take(&mut self.srcs), declare).into_stmt()
+    }
+
+    /// Returns a list of statements that can be used to source type: 'module'
+    ///
+    /// Import and re-export statements are replaced with the setters.
+    ///
+    /// Directives are kept at the top of the module (outside the register call).
+    pub fn build_module(self, id: &str, mut orig_module: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        let mut items = take_directives(&mut orig_module, ModuleItem::as_stmt);
+        let stmts = orig_module
+            .into_iter()
+            .filter_map(|item| match item {
+                ModuleItem::Stmt(stmt) => Some(stmt),
+                ModuleItem::ModuleDecl(_) => None,
+            })
+            .collect();
+
+        items.push(self.build(id, stmts).into());
+        items
+    }
+
+    /// Returns a list of statements that can be used to source type: 'script'
+    pub fn build_script(self, id: &str, mut orig_script: Vec<Stmt>) -> Vec<Stmt> {
+        let mut stmts = take_directives(&mut orig_script, |stmt| Some(stmt));
+
+        stmts.push(self.build(id, orig_script));
+        stmts
+    }
+}
+
+/// Statements of the declare function and the `execute` function.
+struct SplitStmts {
+    /// Variables declared in the declare function
+    vars: Vec<Ident>,
+    /// Function declarations and their export calls
+    declare_stmts: Vec<Stmt>,
+    /// Statements of the `execute` function
+    execute_stmts: Vec<Stmt>,
+}
+
+/// Returns a function expression.
+///
+/// ```js
+/// // Code
+/// function (param1, param2) { stmts }
+/// ```
+fn to_fn_expr(params: Vec<Ident>, stmts: Vec<Stmt>, is_async: bool) -> Expr {
+    Function {
+        params: params
+            .into_iter()
+            .map(|ident| Param::from(Pat::from(ident)))
+            .collect(),
+        body: Some(BlockStmt {
+            stmts,
+            ..Default::default()
+        }),
+        is_async,
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Wraps the expression with the export calls of the binding (if exported)
+fn to_exports_expr(
+    export_ident: &Ident,
+    exp_names: &AHashMap<Id, Vec<Atom>>,
+    id: &Id,
+    expr: Expr,
+) -> Expr {
+    match exp_names.get(id) {
+        Some(names) => names.iter().fold(expr, |expr, name| {
+            system_export_call(export_ident, name, expr)
+        }),
+        None => expr,
+    }
+}
+
+/// Hoists the module scope bindings to the declare function.
+///
+/// Top-level declarations and `var` declarations outside of the functions
+/// are replaced with assignments, and their identifiers are collected.
+///
+/// ```js
+/// // Code
+/// const foo = 1;
+/// class Bar {}
+/// for (var i = 0; i < 1; i++) {}
+///
+/// // Transformed (`var foo, Bar, i;` is declared in the declare function)
+/// foo = 1;
+/// Bar = class Bar {};
+/// for (i = 0; i < 1; i++) {}
+/// ```
+#[derive(Default)]
+struct VarHoister {
+    /// Hoisted identifiers
+    idents: Vec<Ident>,
+}
+
+impl VarHoister {
+    /// Hoists the top-level declaration and returns the statement that replaces it
+    fn hoist_decl(&mut self, decl: Decl) -> Option<Stmt> {
+        match decl {
+            Decl::Class(ClassDecl { ident, class, .. }) => {
+                let span = class.span;
+                let class_expr = Expr::Class(ClassExpr {
+                    ident: Some(ident.clone()),
+                    class,
+                });
+
+                self.hoist(ident.clone());
+
+                Some(
+                    ExprStmt {
+                        expr: Box::new(class_expr.make_assign_to(op!("="), ident.into())),
+                        span,
+                    }
+                    .into(),
+                )
+            }
+            Decl::Var(var_decl) => {
+                let span = var_decl.span;
+
+                self.hoist_var_decl(*var_decl).map(|expr| {
+                    ExprStmt {
+                        expr: Box::new(expr),
+                        span,
+                    }
+                    .into()
+                })
+            }
+            decl => Some(decl.into()),
+        }
+    }
+
+    /// Hoists the identifiers of the variable declaration
+    /// and returns the assignments of the initialized declarators
+    fn hoist_var_decl(&mut self, var_decl: VarDecl) -> Option<Expr> {
+        let mut assign_exprs = Vec::new();
+
+        for decl in var_decl.decls {
+            find_pat_ids::<_, Ident>(&decl.name)
+                .into_iter()
+                .for_each(|ident| self.hoist(ident));
+
+            if let Some(init) = decl.init {
+                let mut init = init;
+
+                init.visit_mut_with(self);
+                assign_exprs.push(Box::new(Expr::Assign(AssignExpr {
+                    op: op!("="),
+                    left: decl
+                        .name
+                        .try_into()
+                        .expect("invalid variable declarator binding"),
+                    right: init,
+                    span: DUMMY_SP,
+                })));
+            }
+        }
+
+        match assign_exprs.len() {
+            0 => None,
+            1 => assign_exprs.pop().map(|expr| *expr),
+            _ => Some(Expr::Seq(SeqExpr {
+                exprs: assign_exprs,
+                span: DUMMY_SP,
+            })),
+        }
+    }
+
+    fn hoist(&mut self, ident: Ident) {
+        if !self
+            .idents
+            .iter()
+            .any(|hoisted| hoisted.to_id() == ident.to_id())
+        {
+            self.idents.push(ident);
+        }
+    }
+
+    /// Returns the `var` declaration to hoist
+    fn as_var_decl(var_decl: &VarDecl) -> bool {
+        var_decl.kind == VarDeclKind::Var && !var_decl.declare
+    }
+}
+
+impl VisitMut for VarHoister {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        stmt.visit_mut_children_with(self);
+
+        if let Stmt::Decl(Decl::Var(var_decl)) = stmt {
+            if Self::as_var_decl(var_decl) {
+                let span = var_decl.span;
+
+                *stmt = match self.hoist_var_decl(*var_decl.take()) {
+                    Some(expr) => ExprStmt {
+                        expr: Box::new(expr),
+                        span,
+                    }
+                    .into(),
+                    None => EmptyStmt { span }.into(),
+                };
+            }
+        }
+    }
+
+    fn visit_mut_for_stmt(&mut self, for_stmt: &mut ForStmt) {
+        for_stmt.visit_mut_children_with(self);
+
+        if let Some(VarDeclOrExpr::VarDecl(var_decl)) = &mut for_stmt.init {
+            if Self::as_var_decl(var_decl) {
+                for_stmt.init = self
+                    .hoist_var_decl(*var_decl.take())
+                    .map(|expr| VarDeclOrExpr::Expr(Box::new(expr)));
+            }
+        }
+    }
+
+    fn visit_mut_for_head(&mut self, for_head: &mut ForHead) {
+        for_head.visit_mut_children_with(self);
+
+        if let ForHead::VarDecl(var_decl) = for_head {
+            if Self::as_var_decl(var_decl) && var_decl.decls.len() == 1 {
+                let decl = var_decl.decls.remove(0);
+
+                find_pat_ids::<_, Ident>(&decl.name)
+                    .into_iter()
+                    .for_each(|ident| self.hoist(ident));
+
+                *for_head = ForHead::Pat(Box::new(decl.name));
+            }
+        }
+    }
+
+    // `var` declarations of the nested functions are not hoisted.
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_getter_prop(&mut self, _: &mut GetterProp) {}
+
+    fn visit_mut_setter_prop(&mut self, _: &mut SetterProp) {}
+}
+
+/// Updates the exported bindings on every assignment and
+/// replaces the module context references with the SystemJS context.
+///
+/// ```js
+/// // Code
+/// foo = 1;
+/// foo++;
+/// [foo] = [1];
+/// for (foo of list) {}
+/// import('./bar');
+/// import.meta;
+///
+/// // Transformed
+/// _export("foo", foo = 1);
+/// (__tmp = foo++, _export("foo", foo), __tmp);
+/// [foo] = [1], _export("foo", foo);
+/// for (foo of list) { _export("foo", foo); }
+/// _context.import('./bar');
+/// _context.meta;
+/// ```
+struct ExportsTracker<'a> {
+    export_ident: &'a Ident,
+    context_ident: &'a Ident,
+    temp_ident: &'a Ident,
+    exp_names: &'a AHashMap<Id, Vec<Atom>>,
+    paths: &'a Option<AHashMap<String, String>>,
+    /// Whether the temporary identifier is used
+    uses_temp: bool,
+}
+
+impl ExportsTracker<'_> {
+    fn is_exported(&self, ident: &Ident) -> bool {
+        self.exp_names.contains_key(&ident.to_id())
+    }
+
+    fn wrap_exports(&self, ident: &Ident, expr: Expr) -> Expr {
+        to_exports_expr(self.export_ident, self.exp_names, &ident.to_id(), expr)
+    }
+
+    /// Exports the bindings of the loop head at the beginning of each iteration
+    ///
+    /// ```js
+    /// // Code
+    /// for (foo in obj) body;
+    ///
+    /// // Transformed
+    /// for (foo in obj) { _export("foo", foo); body; }
+    /// ```
+    fn track_for_head(&self, head: &ForHead, body: &mut Box<Stmt>) {
+        let ForHead::Pat(pat) = head else {
+            return;
+        };
+
+        let exports = find_pat_ids::<_, Ident>(pat)
+            .into_iter()
+            .filter(|ident| self.is_exported(ident))
+            .map(|ident| self.wrap_exports(&ident, ident.clone().into()).into_stmt())
+            .collect::<Vec<_>>();
+
+        if exports.is_empty() {
+            return;
+        }
+
+        match &mut **body {
+            Stmt::Block(block) => {
+                block.stmts.splice(0..0, exports);
+            }
+            stmt => {
+                *body = Box::new(
+                    BlockStmt {
+                        stmts: exports.into_iter().chain([stmt.take()]).collect(),
+                        ..Default::default()
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+}
+
+impl VisitMut for ExportsTracker<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        stmt.visit_mut_children_with(self);
+
+        // Destructuring assignments (the value of the expression is not used)
+        let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+            return;
+        };
+
+        let Expr::Assign(AssignExpr {
+            left: AssignTarget::Pat(pat),
+            ..
+        }) = &**expr
+        else {
+            return;
+        };
+
+        let exports = find_pat_ids::<_, Ident>(pat)
+            .into_iter()
+            .filter(|ident| self.is_exported(ident))
+            .map(|ident| Box::new(self.wrap_exports(&ident, ident.clone().into())))
+            .collect::<Vec<_>>();
+
+        if !exports.is_empty() {
+            *expr = Box::new(Expr::Seq(SeqExpr {
+                exprs: std::iter::once(expr.take()).chain(exports).collect(),
+                span: DUMMY_SP,
+            }));
+        }
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, for_in_stmt: &mut ForInStmt) {
+        for_in_stmt.visit_mut_children_with(self);
+        self.track_for_head(&for_in_stmt.left, &mut for_in_stmt.body);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, for_of_stmt: &mut ForOfStmt) {
+        for_of_stmt.visit_mut_children_with(self);
+        self.track_for_head(&for_of_stmt.left, &mut for_of_stmt.body);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        match expr {
+            // foo = 1;
+            Expr::Assign(AssignExpr {
+                left: AssignTarget::Simple(SimpleAssignTarget::Ident(binding)),
+                ..
+            }) if self.is_exported(&binding.id) => {
+                let ident = binding.id.clone();
+                *expr = self.wrap_exports(&ident, expr.take());
+            }
+            // ++foo; foo++;
+            Expr::Update(UpdateExpr { arg, prefix, .. }) => {
+                let Expr::Ident(ident) = &**arg else {
+                    return;
+                };
+
+                if !self.is_exported(ident) {
+                    return;
+                }
+
+                let ident = ident.clone();
+
+                if *prefix {
+                    *expr = self.wrap_exports(&ident, expr.take());
+                } else {
+                    // Exports the updated value and returns the previous one.
+                    let temp = self.temp_ident.clone();
+
+                    self.uses_temp = true;
+                    *expr = Expr::Seq(SeqExpr {
+                        exprs: vec![
+                            Box::new(expr.take().make_assign_to(op!("="), temp.clone().into())),
+                            Box::new(self.wrap_exports(&ident, ident.clone().into())),
+                            Box::new(temp.into()),
+                        ],
+                        span: DUMMY_SP,
+                    });
+                }
+            }
+            // import('./foo');
+            Expr::Call(CallExpr {
+                callee: Callee::Import(_),
+                args,
+                ..
+            }) => {
+                if let Some(Expr::Lit(lit @ Lit::Str(_))) = args.first().map(|arg| &*arg.expr) {
+                    let src = to_mapped_src(&lit_to_string(lit), self.paths);
+                    *expr = system_import_call(self.context_ident, Lit::Str(src.into()));
+                }
+            }
+            // import.meta
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }) => {
+                *expr = self
+                    .context_ident
+                    .clone()
+                    .make_member(quote_ident!("meta"))
+                    .into();
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,13 +1,24 @@
 use std::mem;
 
-use crate::{module_builder::ModuleBuilder, module_collector::create_collector};
+#[cfg(feature = "compiler")]
+use crate::graph::CollectedDeps;
+use crate::{
+    bindings::LocalBindings,
+    idents::{collect_used_syms, Idents},
+    module_builder::ModuleBuilder,
+    module_collector::create_collector,
+    module_kind::{detect_resolved_module_kind, ModuleKind},
+    options::{Options, OutputFormat},
+    scope::ensure_resolved,
+    system_module_builder::SystemModuleBuilder,
+};
 use swc_core::{
-    common::{collections::AHashMap, SyntaxContext},
+    common::{collections::AHashMap, Span, Spanned, SyntaxContext},
     ecma::{
         ast::*,
-        utils::private_ident,
         visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
     },
+    plugin::errors::HANDLER,
 };
 
 pub struct GlobalModuleTransformer {
@@ -17,10 +28,15 @@ pub struct GlobalModuleTransformer {
     runtime: bool,
     /// Paths
     paths: Option<AHashMap<String, String>>,
-    /// Context identifier
-    ctx_ident: Ident,
+    /// Options
+    options: Options,
     /// Unresolved context
     unresolved_ctxt: SyntaxContext,
+    /// Module kind of the program
+    kind: ModuleKind,
+    /// Dependencies collected while transforming (read by the compiler APIs)
+    #[cfg(feature = "compiler")]
+    collected_deps: CollectedDeps,
 }
 
 impl GlobalModuleTransformer {
@@ -28,48 +44,173 @@ impl GlobalModuleTransformer {
         id: String,
         runtime: bool,
         paths: Option<AHashMap<String, String>>,
+        options: Options,
         unresolved_ctxt: SyntaxContext,
     ) -> Self {
         Self {
             id,
             runtime,
             paths,
+            options,
             unresolved_ctxt,
-            ctx_ident: private_ident!("__context"),
+            kind: ModuleKind::Cjs,
+            #[cfg(feature = "compiler")]
+            collected_deps: CollectedDeps::default(),
         }
     }
+
+    /// Returns the module kind of the transformed program
+    #[cfg(feature = "compiler")]
+    pub fn kind(&self) -> ModuleKind {
+        self.kind
+    }
+
+    /// Takes the dependencies collected while transforming the program
+    #[cfg(feature = "compiler")]
+    pub(crate) fn take_collected_deps(&mut self) -> CollectedDeps {
+        mem::take(&mut self.collected_deps)
+    }
+}
+
+impl GlobalModuleTransformer {
+    /// Returns `true` if the collector rewrites the module references for the runtime phase
+    ///
+    /// The SystemJS format references the dependencies through its own context instead.
+    fn collects_runtime(&self) -> bool {
+        self.runtime && self.options.format == OutputFormat::GlobalModules
+    }
 }
 
 impl VisitMut for GlobalModuleTransformer {
     noop_visit_mut_type!();
 
+    fn visit_mut_program(&mut self, program: &mut Program) {
+        if let Err(message) = self.options.verify() {
+            HANDLER.with(|handler| {
+                handler.struct_span_err(program.span(), &message).emit();
+            });
+            return;
+        }
+
+        self.unresolved_ctxt = ensure_resolved(program, self.unresolved_ctxt);
+        self.kind = detect_resolved_module_kind(program, self.unresolved_ctxt);
+
+        if self.kind == ModuleKind::Mixed {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_warn(
+                        program.span(),
+                        "mixed ESModule and CommonJS usage, CommonJS exports are not registered",
+                    )
+                    .emit();
+            });
+        }
+
+        program.visit_mut_children_with(self);
+    }
+
     fn visit_mut_module(&mut self, module: &mut Module) {
+        let idents = Idents::new(&self.options.identifiers, &collect_used_syms(module));
+        let ctx_ident = idents.ctx_ident();
         let mut collector = create_collector(
             self.unresolved_ctxt,
-            self.runtime,
-            &self.ctx_ident,
+            self.collects_runtime(),
+            self.kind,
+            &ctx_ident,
+            &idents,
             &self.paths,
+            &self.options.externals,
         );
-        let mut builder = ModuleBuilder::new(&self.ctx_ident);
 
         module.visit_mut_children_with(&mut collector);
-        builder.collect(&mut collector);
 
-        module.body = builder.build_module(&self.id, self.runtime, mem::take(&mut module.body));
+        #[cfg(feature = "compiler")]
+        {
+            self.collected_deps = CollectedDeps::from_collector(&collector);
+        }
+
+        if self.options.format == OutputFormat::System {
+            let mut builder = SystemModuleBuilder::new(&idents, &self.paths);
+
+            ensure_system_compatible(module.span(), collector.cjs_exports);
+            builder.collect(&mut collector);
+
+            module.body = builder.build_module(&self.id, mem::take(&mut module.body));
+            return;
+        }
+
+        let mut builder = ModuleBuilder::new(
+            &ctx_ident,
+            &idents,
+            self.options.compact,
+            self.options.used_exports.get(&self.id),
+        );
+
+        builder.collect(&mut collector, &LocalBindings::collect(&module.body));
+
+        module.body = builder.build_module(
+            &self.id,
+            self.runtime,
+            self.options.register_placement,
+            mem::take(&mut module.body),
+        );
     }
 
     fn visit_mut_script(&mut self, script: &mut Script) {
+        let idents = Idents::new(&self.options.identifiers, &collect_used_syms(script));
+        let ctx_ident = idents.ctx_ident();
         let mut collector = create_collector(
             self.unresolved_ctxt,
-            self.runtime,
-            &self.ctx_ident,
+            self.collects_runtime(),
+            self.kind,
+            &ctx_ident,
+            &idents,
             &self.paths,
+            &self.options.externals,
+        );
+
+        script.visit_mut_with(&mut collector);
+
+        #[cfg(feature = "compiler")]
+        {
+            self.collected_deps = CollectedDeps::from_collector(&collector);
+        }
+
+        if self.options.format == OutputFormat::System {
+            let mut builder = SystemModuleBuilder::new(&idents, &self.paths);
+
+            ensure_system_compatible(script.span(), collector.cjs_exports);
+            builder.collect(&mut collector);
+
+            script.body = builder.build_script(&self.id, mem::take(&mut script.body));
+            return;
+        }
+
+        let mut builder = ModuleBuilder::new(
+            &ctx_ident,
+            &idents,
+            self.options.compact,
+            self.options.used_exports.get(&self.id),
         );
-        let mut builder = ModuleBuilder::new(&self.ctx_ident);
 
-        script.visit_mut_children_with(&mut collector);
-        builder.collect(&mut collector);
+        builder.collect(&mut collector, &LocalBindings::default());
 
         script.body = builder.build_script(&self.id, mem::take(&mut script.body));
     }
 }
+
+/// Reports an error if the CommonJS exports are used in the SystemJS format.
+///
+/// SystemJS modules can only be declared with the ESModule exports.
+fn ensure_system_compatible(span: Span, cjs_exports: bool) {
+    if cjs_exports {
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(
+                    span,
+                    "CommonJS exports are not supported in the SystemJS format",
+                )
+                .emit();
+        });
+    }
+}
@@ -0,0 +1,89 @@
+use swc_core::common::collections::AHashMap;
+
+use crate::{
+    compiler::{
+        check_wrapped_outputs, transform_sorted, ModuleSource, TransformError, TransformOutput,
+    },
+    graph::ModuleId,
+    options::Options,
+};
+
+/// Header prefix of the update chunk.
+///
+/// ```js
+/// // @global-modules-update ["1001","1000"]
+/// ```
+pub const UPDATE_HEADER_PREFIX: &str = "// @global-modules-update ";
+
+/// Update chunk for hot module replacement.
+#[derive(Debug, Clone)]
+pub struct UpdateChunk {
+    /// Module IDs in the execution order (dependencies come first)
+    pub ids: Vec<ModuleId>,
+    /// Chunk code
+    pub code: String,
+}
+
+/// Transforms the changed modules in runtime phase and concatenates them into one update chunk.
+///
+/// Each module is wrapped with its own function scope and ordered by dependencies,
+/// so re-registering the modules (`global.__modules.register(id)`) follows the dependency order.
+///
+/// Same as the bundle, modules containing top-level `await` can't be wrapped,
+/// and the external dependencies must be provided by the host accessor (`externals.accessor`).
+///
+/// ```js
+/// // @global-modules-update ["1001","1000"]
+/// // 1001
+/// (function () {
+///   const __context = global.__modules.register("1001");
+///   // ...
+/// })();
+/// // 1000
+/// (function () {
+///   const __context = global.__modules.register("1000");
+///   // ...
+/// })();
+/// ```
+pub fn emit_update(
+    sources: &[ModuleSource],
+    paths: &Option<AHashMap<String, String>>,
+    options: &Options,
+) -> Result<UpdateChunk, TransformError> {
+    let outputs = transform_sorted(sources, paths, options)?;
+
+    check_wrapped_outputs(sources, &outputs, options, "update chunks")?;
+
+    let ids = outputs
+        .iter()
+        .map(|output| output.id.clone())
+        .collect::<Vec<ModuleId>>();
+
+    let mut code = format!(
+        "{}{}\n",
+        UPDATE_HEADER_PREFIX,
+        serde_json::to_string(&ids).expect("failed to serialize module ids")
+    );
+
+    for output in &outputs {
+        code.push_str(&wrap_module(output));
+    }
+
+    Ok(UpdateChunk { ids, code })
+}
+
+/// Wraps the transformed module code with a function scope.
+///
+/// ```js
+/// // id
+/// (function () {
+///   code
+/// })();
+/// ```
+fn wrap_module(output: &TransformOutput) -> String {
+    format!(
+        "// {}\n(function () {{\n{}}})();\n",
+        output.id.replace('\n', " "),
+        output.code
+    )
+}
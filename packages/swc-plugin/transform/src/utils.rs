@@ -1,78 +1,97 @@
 pub mod ast {
-    use crate::models::*;
+    use crate::{idents::Idents, models::*};
     use core::panic;
     use swc_core::{
         atoms::Atom,
-        common::{collections::AHashMap, Spanned, SyntaxContext, DUMMY_SP},
+        common::{Span, SyntaxContext, DUMMY_SP},
         ecma::{
             ast::*,
-            utils::{private_ident, ExprFactory},
+            utils::{find_pat_ids, ExprFactory},
+            visit::{noop_visit_mut_type, VisitMut, VisitMutWith},
         },
         plugin::errors::HANDLER,
     };
 
-    use super::helpers::to_mapped_src;
+    use super::presets::{default_name_call, require_fn};
 
-    /// Returns a binding identifier for the default export.
+    /// Returns a key-value property.
+    /// Can be used to create a assign expression.
     ///
     /// ```js
     /// // Code
-    /// __default;
+    /// var { key: value } = value;
     /// ```
-    pub fn anonymous_default_binding_ident() -> Ident {
-        private_ident!("__default")
+    pub fn kv_prop(key: Atom, value: Expr) -> PropOrSpread {
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: str_prop_name(key),
+            value: value.into(),
+        })))
     }
 
-    /// Returns a binding identifier.
+    /// Returns a getter property.
     ///
     /// ```js
     /// // Code
-    /// __x;
+    /// var value = { get key() { return value; } };
     /// ```
-    pub fn exp_binding_ident() -> Ident {
-        private_ident!("__x")
+    pub fn getter_prop(key: PropName, value: Expr) -> PropOrSpread {
+        PropOrSpread::Prop(Box::new(Prop::Getter(GetterProp {
+            key,
+            type_ann: None,
+            body: Some(BlockStmt {
+                stmts: vec![Stmt::Return(ReturnStmt {
+                    arg: Some(value.into()),
+                    span: DUMMY_SP,
+                })],
+                ..Default::default()
+            }),
+            span: DUMMY_SP,
+        })))
     }
 
-    /// Returns a module identifier.
-    ///
-    /// ```js
-    /// // Code
-    /// __mod;
-    /// ```
-    pub fn mod_ident() -> Ident {
-        private_ident!("__mod")
+    /// Returns a string property name.
+    pub fn str_prop_name(key: Atom) -> PropName {
+        PropName::Str(Str {
+            value: key,
+            raw: None,
+            span: DUMMY_SP,
+        })
     }
 
-    /// Returns a key-value property.
-    /// Can be used to create a assign expression.
+    /// Returns a shortest form of the key-value property.
     ///
     /// ```js
     /// // Code
-    /// var { key: value } = value;
+    /// var value = { key, key: value, "k-e-y": value };
     /// ```
-    pub fn kv_prop(key: Atom, value: Expr) -> PropOrSpread {
-        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-            key: PropName::Str(Str {
-                value: key,
-                raw: None,
-                span: DUMMY_SP,
-            }),
-            value: value.into(),
-        })))
+    pub fn compact_prop(key: Atom, value: Ident) -> PropOrSpread {
+        let prop = if key == value.sym {
+            Prop::Shorthand(value)
+        } else {
+            Prop::KeyValue(KeyValueProp {
+                key: compact_prop_name(key),
+                value: value.into(),
+            })
+        };
+
+        PropOrSpread::Prop(Box::new(prop))
     }
 
-    /// Returns a spread property.
-    /// Can be used to create a assign expression.
-    ///
-    /// ```js
-    /// // Code
-    /// var { ...expr } = value;
-    /// ```
-    pub fn spread_prop(expr: Expr) -> PropOrSpread {
-        PropOrSpread::Spread(SpreadElement {
-            expr: expr.into(),
-            ..Default::default()
-        })
+    /// Checks whether it is a valid identifier name (reserved words are allowed).
+    pub fn is_ident_name(name: &str) -> bool {
+        let mut chars = name.chars();
+
+        chars.next().is_some_and(Ident::is_valid_start) && chars.all(Ident::is_valid_continue)
+    }
+
+    /// Returns an identifier property name if the key is a valid identifier name
+    /// (reserved words are allowed as property names), otherwise a string property name.
+    pub fn compact_prop_name(key: Atom) -> PropName {
+        if is_ident_name(&key) {
+            PropName::Ident(IdentName::new(key, DUMMY_SP))
+        } else {
+            str_prop_name(key)
+        }
     }
 
     /// Returns an object key-value property.
@@ -82,9 +101,9 @@ pub mod ast {
     /// // Code
     /// var value = { key: value };
     /// ```
-    pub fn obj_kv_prop(key: Ident, value: Ident) -> ObjectPatProp {
+    pub fn obj_kv_prop(key: Atom, value: Ident) -> ObjectPatProp {
         ObjectPatProp::KeyValue(KeyValuePatProp {
-            key: PropName::Ident(key.into()),
+            key: compact_prop_name(key),
             value: Box::new(Pat::Ident(value.into())),
         })
     }
@@ -104,21 +123,6 @@ pub mod ast {
         })
     }
 
-    /// Returns an assign expression.
-    ///
-    /// ```js
-    /// // Code
-    /// ident = expr;
-    /// ```
-    pub fn assign_expr(ident: Ident, expr: Expr) -> AssignExpr {
-        AssignExpr {
-            left: AssignTarget::Simple(SimpleAssignTarget::Ident(ident.into())),
-            right: expr.into(),
-            op: AssignOp::Assign,
-            ..Default::default()
-        }
-    }
-
     /// Returns an object literal expression.
     ///
     /// ```js
@@ -138,8 +142,8 @@ pub mod ast {
     /// // Code
     /// 'foo'
     /// ```
-    pub fn str_lit(str: &String) -> Lit {
-        Lit::from(str.as_str())
+    pub fn str_lit(str: &str) -> Lit {
+        Lit::from(str)
     }
 
     /// Returns a string from the given literal.
@@ -189,17 +193,17 @@ pub mod ast {
         .into()
     }
 
-    /// Wraps the given expression with a `ctx_ident.exports.ns` function call expression.
+    /// Wraps the given expressions with a `ctx_ident.exports.ns` function call expression.
     ///
     /// ```js
     /// // Code
-    /// ctx_ident.exports.ns(expr);
+    /// ctx_ident.exports.ns(expr1, expr2);
     /// ```
-    pub fn to_ns_export(ctx_ident: Ident, expr: Expr) -> Expr {
+    pub fn to_ns_export(ctx_ident: Ident, exprs: Vec<Expr>) -> Expr {
         ctx_ident
             .make_member("exports".into())
             .make_member("ns".into())
-            .as_call(DUMMY_SP, vec![expr.into()])
+            .as_call(DUMMY_SP, exprs.into_iter().map(Into::into).collect())
     }
 
     /// Checks whether it is a CommonJS `require` function call.
@@ -229,16 +233,306 @@ pub mod ast {
         }
     }
 
-    /// Checks whether it is a member expression of a CommonJS module.
+    /// Checks whether it is an AMD `define` function call.
+    ///
+    /// ```js
+    /// // Case 1.
+    /// define(['./foo'], function (foo) { ... }); // true
+    ///
+    /// // Case 2.
+    /// function foo(define) {
+    ///   define(['./foo'], function (foo) { ... }); // false
+    /// }
+    /// ```
+    pub fn is_define_call(unresolved_ctxt: SyntaxContext, call_expr: &CallExpr) -> bool {
+        // `define` call must have the factory argument (`define(id?, deps?, factory)`)
+        if call_expr.args.is_empty() || call_expr.args.len() > 3 {
+            return false;
+        }
+
+        match &call_expr.callee {
+            Callee::Expr(callee_expr) => {
+                // Check callee name is `define` and its context is unresolved (global identifier)
+                callee_expr.is_ident_ref_to("define")
+                    && callee_expr.as_ident().unwrap().ctxt == unresolved_ctxt
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks whether it is a `typeof define` expression that refers to the global `define`.
     ///
     /// ```js
     /// // Code
-    /// exports.foo; // true;
+    /// typeof define === 'function' && define.amd; // `typeof define` is true
     /// ```
-    pub fn is_cjs_exp_member(unresolved_ctxt: SyntaxContext, member_expr: &MemberExpr) -> bool {
-        // Check object is `exports` and its context is unresolved (global identifier)
-        member_expr.obj.is_ident_ref_to("exports")
-            && member_expr.obj.as_ident().unwrap().ctxt == unresolved_ctxt
+    pub fn is_typeof_define(unresolved_ctxt: SyntaxContext, unary_expr: &UnaryExpr) -> bool {
+        unary_expr.op == UnaryOp::TypeOf
+            && unary_expr.arg.is_ident_ref_to("define")
+            && unary_expr.arg.as_ident().unwrap().ctxt == unresolved_ctxt
+    }
+
+    /// Returns the source of the module relative URL expression that refers to the global `URL`.
+    ///
+    /// ```js
+    /// // Code
+    /// new URL('./foo.png', import.meta.url); // Some("./foo.png")
+    /// new URL('./foo.png', location.href);   // None
+    /// ```
+    pub fn as_url_src(unresolved_ctxt: SyntaxContext, new_expr: &NewExpr) -> Option<String> {
+        if !new_expr.callee.is_ident_ref_to("URL")
+            || new_expr.callee.as_ident().unwrap().ctxt != unresolved_ctxt
+        {
+            return None;
+        }
+
+        match new_expr.args.as_deref() {
+            Some(
+                [ExprOrSpread {
+                    expr: src,
+                    spread: None,
+                }, ExprOrSpread {
+                    expr: base,
+                    spread: None,
+                }],
+            ) if is_import_meta_url(base) => match &**src {
+                Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks whether it is an `import.meta` expression.
+    pub fn is_import_meta(expr: &Expr) -> bool {
+        matches!(
+            expr,
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            })
+        )
+    }
+
+    /// Checks whether it is an `import.meta.<name>` expression.
+    fn is_import_meta_member(expr: &Expr, name: &str) -> bool {
+        match expr {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => prop.sym == name && is_import_meta(obj),
+            _ => false,
+        }
+    }
+
+    /// Checks whether it is an `import.meta.url` expression.
+    fn is_import_meta_url(expr: &Expr) -> bool {
+        is_import_meta_member(expr, "url")
+    }
+
+    /// Returns the specifier of the `import.meta.resolve` call.
+    ///
+    /// ```js
+    /// // Code
+    /// import.meta.resolve('./foo'); // Some("./foo")
+    /// import.meta.resolve(name);    // None
+    /// ```
+    pub fn as_import_meta_resolve_specifier(call_expr: &CallExpr) -> Option<String> {
+        let Callee::Expr(callee) = &call_expr.callee else {
+            return None;
+        };
+
+        if !is_import_meta_member(callee, "resolve") {
+            return None;
+        }
+
+        match call_expr.args.first() {
+            Some(ExprOrSpread { expr, spread: None }) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks whether it is a worker constructor call that refers to the global `Worker` or `SharedWorker`.
+    ///
+    /// ```js
+    /// // Code
+    /// new Worker(new URL('./worker.js', import.meta.url));
+    /// new SharedWorker(new URL('./worker.js', import.meta.url));
+    /// ```
+    pub fn is_worker_new_expr(unresolved_ctxt: SyntaxContext, new_expr: &NewExpr) -> bool {
+        (new_expr.callee.is_ident_ref_to("Worker")
+            || new_expr.callee.is_ident_ref_to("SharedWorker"))
+            && new_expr.callee.as_ident().unwrap().ctxt == unresolved_ctxt
+    }
+
+    /// Converts the AMD `define` call into the CommonJS module exports assignment.
+    ///
+    /// The special dependencies (`require`, `exports` and `module`) are replaced with
+    /// the CommonJS module's ones, and the other dependencies are required.
+    ///
+    /// ```js
+    /// // Given code
+    /// define(['./foo', 'exports'], function (foo, exports) { ... });
+    /// define(function (require, exports, module) { ... });
+    /// define({ foo: 'foo' });
+    ///
+    /// // Converted code
+    /// module.exports = function (foo, exports) { ... }(require('./foo'), exports) ?? module.exports;
+    /// module.exports = function (require, exports, module) { ... }(require, exports, module) ?? module.exports;
+    /// module.exports = { foo: 'foo' };
+    /// ```
+    pub fn define_call_as_cjs_expr(
+        unresolved_ctxt: SyntaxContext,
+        call_expr: &CallExpr,
+        runtime: bool,
+    ) -> Option<Expr> {
+        let global_ident = |sym: &str| Ident::new(sym.into(), DUMMY_SP, unresolved_ctxt);
+        let cjs_module_exports = || {
+            global_ident("module").make_member(IdentName {
+                sym: "exports".into(),
+                ..Default::default()
+            })
+        };
+        let to_dep_expr = |src: &str| -> Expr {
+            match src {
+                "require" if runtime => require_fn(),
+                "require" | "exports" | "module" => global_ident(src).into(),
+                _ => global_ident("require").as_call(DUMMY_SP, vec![str_lit(src).as_arg()]),
+            }
+        };
+
+        if call_expr.args.iter().any(|arg| arg.spread.is_some()) {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(call_expr.span, "unsupported define call")
+                    .emit();
+            });
+            return None;
+        }
+
+        let (factory, rest) = call_expr.args.split_last()?;
+        let mut factory = *factory.expr.clone();
+
+        // Bound identifiers of the factory parameters (`None` if the factory is not a function)
+        let param_ids = match factory.unwrap_parens() {
+            Expr::Fn(FnExpr { function, .. }) => Some(
+                function
+                    .params
+                    .iter()
+                    .map(|param| param.pat.as_ident().map(|ident| ident.to_id()))
+                    .collect::<Vec<_>>(),
+            ),
+            Expr::Arrow(ArrowExpr { params, .. }) => Some(
+                params
+                    .iter()
+                    .map(|pat| pat.as_ident().map(|ident| ident.to_id()))
+                    .collect(),
+            ),
+            _ => None,
+        };
+
+        let dep_names = match rest.last().map(|arg| &*arg.expr) {
+            // `define(deps, factory)`
+            Some(Expr::Array(ArrayLit { elems, .. })) => {
+                let mut dep_names = Vec::with_capacity(elems.len());
+
+                for elem in elems {
+                    match elem.as_ref().map(|elem| (&*elem.expr, elem.spread)) {
+                        // The dependencies of the `define` function must be a string type only.
+                        Some((Expr::Lit(Lit::Str(str_lit)), None)) => {
+                            dep_names.push(str_lit.value.to_string())
+                        }
+                        _ => {
+                            HANDLER.with(|handler| {
+                                handler
+                                    .struct_span_err(call_expr.span, "invalid define dependencies")
+                                    .emit();
+                            });
+                            return None;
+                        }
+                    }
+                }
+
+                Some(dep_names)
+            }
+            // `define(function (require, exports, module) { ... })`
+            _ => param_ids.as_ref().map(|param_ids| {
+                ["require", "exports", "module"]
+                    .into_iter()
+                    .take(param_ids.len())
+                    .map(String::from)
+                    .collect()
+            }),
+        };
+
+        // The `require` parameter of the factory refers to the global `require`,
+        // so its calls are collected as the dependencies.
+        //
+        // ```js
+        // define(['require'], function (require) {
+        //   require('./foo'); // Same as the CommonJS's `require('./foo')`
+        // });
+        // ```
+        let require_param_id = dep_names
+            .as_ref()
+            .and_then(|dep_names| dep_names.iter().position(|name| name == "require"))
+            .zip(param_ids)
+            .and_then(|(index, param_ids)| param_ids.into_iter().nth(index).flatten());
+
+        if let Some(id) = require_param_id {
+            factory.visit_mut_with(&mut IdentRetagger {
+                id,
+                ctxt: unresolved_ctxt,
+            });
+        }
+
+        let exports_expr = match dep_names {
+            // Keeps the module exports if the factory returns nothing.
+            Some(dep_names) => factory
+                .as_call(
+                    DUMMY_SP,
+                    dep_names
+                        .iter()
+                        .map(|name| to_dep_expr(name).as_arg())
+                        .collect(),
+                )
+                .make_bin(op!("??"), cjs_module_exports()),
+            // `define(value)`
+            None => factory,
+        };
+
+        Some(assign_member(cjs_module_exports(), exports_expr))
+    }
+
+    /// Replaces the syntax context of the identifiers that refer to the given binding.
+    struct IdentRetagger {
+        id: Id,
+        ctxt: SyntaxContext,
+    }
+
+    impl VisitMut for IdentRetagger {
+        noop_visit_mut_type!();
+
+        fn visit_mut_ident(&mut self, ident: &mut Ident) {
+            if ident.sym == self.id.0 && ident.ctxt == self.id.1 {
+                ident.ctxt = self.ctxt;
+            }
+        }
+    }
+
+    /// Checks whether it is the CommonJS module's `exports` identifier.
+    ///
+    /// ```js
+    /// // Code
+    /// exports; // true;
+    /// ```
+    pub fn is_cjs_exp_ident(unresolved_ctxt: SyntaxContext, ident: &Ident) -> bool {
+        // Check identifier is `exports` and its context is unresolved (global identifier)
+        ident.sym == "exports" && ident.ctxt == unresolved_ctxt
     }
 
     /// Checks whether it is a member expression of a CommonJS module.
@@ -267,59 +561,67 @@ pub mod ast {
         )
     }
 
-    /// Extracts and returns the its ident from the declarations.
+    /// Extracts and returns the bound identifiers from the declarations.
     ///
     /// ```js
     /// // Code
     /// function foo {} // foo
     /// class Bar {} // Bar
     /// const baz = expr; // baz
+    /// const a = 1, b = 2; // a, b
+    /// const { c, d: [e, ...f] } = expr; // c, e, f
     /// ```
-    pub fn get_ident_from_decl(decl: &Decl) -> Option<Ident> {
+    pub fn get_idents_from_decl(decl: &Decl) -> Vec<Ident> {
         match decl {
             Decl::Class(ClassDecl {
                 ident,
                 declare: false,
                 ..
-            }) => Some(ident.clone()),
+            }) => vec![ident.clone()],
             Decl::Fn(FnDecl {
                 ident,
                 declare: false,
                 ..
-            }) => Some(ident.clone()),
-            Decl::Var(val_decl) => {
-                if val_decl.decls.len() != 1 {
-                    HANDLER.with(|handler| {
-                        handler
-                            .struct_span_err(
-                                val_decl.span,
-                                "multiple variable declarations are not allowed",
-                            )
-                            .emit();
-                    });
-                    panic!(); // FIXME
-                }
+            }) => vec![ident.clone()],
+            Decl::Var(var_decl) if !var_decl.declare => find_pat_ids(&var_decl.decls),
+            _ => Vec::new(),
+        }
+    }
 
-                let var_decl = val_decl.decls.get(0).unwrap();
-
-                match var_decl {
-                    VarDeclarator {
-                        name: Pat::Ident(BindingIdent { id, type_ann: None }),
-                        definite: false,
-                        ..
-                    } => Some(id.clone()),
-                    _ => {
-                        HANDLER.with(|handler| {
-                            handler
-                                .struct_span_err(var_decl.span, "unsupported variable declaration")
-                                .emit();
-                        });
-                        panic!(); // FIXME
-                    }
-                }
-            }
-            _ => None,
+    /// Returns the declaration with the span of the export statement.
+    ///
+    /// Leading comments (eg. JSDoc) are attached to the start of the export statement,
+    /// so they are kept when the `export` keyword is stripped.
+    pub fn with_export_span(mut decl: Decl, span: Span) -> Decl {
+        match &mut decl {
+            Decl::Class(class_decl) => class_decl.class.span = span,
+            Decl::Fn(fn_decl) => fn_decl.function.span = span,
+            Decl::Var(var_decl) => var_decl.span = span,
+            Decl::Using(using_decl) => using_decl.span = span,
+            _ => {}
         }
+
+        decl
+    }
+
+    /// Checks whether the statement is a directive (eg. `'use strict'`, `'use client'`).
+    pub fn is_directive(stmt: &Stmt) -> bool {
+        matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if matches!(&**expr, Expr::Lit(Lit::Str(_))))
+    }
+
+    /// Takes the directive prologue from the beginning of the items.
+    ///
+    /// ```js
+    /// 'use strict';
+    /// 'use client';
+    /// ```
+    pub fn take_directives<T>(items: &mut Vec<T>, as_stmt: impl Fn(&T) -> Option<&Stmt>) -> Vec<T> {
+        let len = items
+            .iter()
+            .take_while(|item| as_stmt(item).is_some_and(is_directive))
+            .count();
+
+        items.drain(..len).collect()
     }
 
     /// Converts to import statement
@@ -351,39 +653,19 @@ pub mod ast {
         .into()
     }
 
-    /// Returns an expression that represents a CommonJS module export name.
-    ///
-    /// ```js
-    /// // Given code
-    /// exports.foo; // Returns "foo"
-    /// ```
-    pub fn to_cjs_export_name(prop: &MemberProp) -> Expr {
-        match prop {
-            MemberProp::Ident(ident) => Expr::Lit(Lit::Str(Str {
-                value: ident.sym.as_str().into(),
-                raw: None,
-                span: DUMMY_SP,
-            })),
-            MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
-                str_lit_expr @ Expr::Lit(Lit::Str(_)) => str_lit_expr.clone(),
-                _ => *expr.clone(),
-            },
-            _ => HANDLER.with(|handler| {
-                handler
-                    .struct_span_err(prop.span(), "unsupported property type")
-                    .emit();
-
-                panic!("fatal error");
-            }),
-        }
-    }
-
     /// Converts an import declaration to a `Dep`.
+    ///
+    /// `to_src` returns the source of the dependency (eg. mapped by `paths`).
     pub fn import_as_dep(
         import_decl: &ImportDecl,
-        paths: &Option<AHashMap<String, String>>,
+        to_src: impl FnOnce(String) -> String,
     ) -> Option<Dep> {
-        let src = to_mapped_src(&import_decl.src.value.to_string(), paths);
+        // Ignore TypeScript type-only imports
+        if import_decl.type_only {
+            return None;
+        }
+
+        let src = to_src(import_decl.src.value.to_string());
         let members = import_decl
             .specifiers
             .iter()
@@ -400,9 +682,10 @@ pub mod ast {
                     ..
                 }) => Some(DepMember::new(
                     local.clone(),
-                    imported.as_ref().map(|name| match name {
-                        ModuleExportName::Ident(ident) => ident.sym.as_str().to_string(),
-                        ModuleExportName::Str(str) => str.value.to_string(),
+                    Some(match imported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                        None => local.sym.to_string(),
                     }),
                 )),
                 // Default import
@@ -425,122 +708,142 @@ pub mod ast {
             })
             .collect::<Vec<DepMember>>();
 
-        // If there are no members, return None
-        if members.is_empty() {
-            None
-        } else {
-            Some(Dep::base(src, members))
-        }
+        // Side-effect only imports (no members) are also kept as a dependency
+        Some(Dep::base(src, members))
     }
 
     /// Converts an export declaration to an `Exp`.
-    pub fn export_decl_as_exp(export_decl: &ExportDecl) -> Option<(Exp, Stmt, ExpBinding)> {
-        // When export declaration has a own identifier.
-        if let Some(decl_ident) = get_ident_from_decl(&export_decl.decl) {
-            let exp_binding_ident = exp_binding_ident();
-            let name = decl_ident.sym.as_str().to_string();
-            let exp = Exp::Base(BaseExp::new(vec![ExpMember::new(
-                exp_binding_ident.clone(),
-                name,
-            )]));
+    pub fn export_decl_as_exp(export_decl: &ExportDecl) -> Option<(Exp, Stmt)> {
+        let members = get_idents_from_decl(&export_decl.decl)
+            .into_iter()
+            .map(|decl_ident| {
+                let name = decl_ident.sym.as_str().to_string();
+                ExpMember::new(decl_ident, name)
+            })
+            .collect::<Vec<ExpMember>>();
 
+        // When export declaration has own identifiers.
+        if members.is_empty() {
+            None
+        } else {
             Some((
-                exp,
+                Exp::Base(BaseExp::new(members)),
                 // Keep the original export declaration
-                Stmt::Decl(export_decl.decl.clone()),
-                // Create binding to reference the export declaration's identifier
-                //
-                // ```js
-                // // Given code
-                // export function foo() {}
-                // ```
-                // - binding_ident: __x
-                // - expr: foo (decl_ident)
-                ExpBinding {
-                    binding_ident: exp_binding_ident,
-                    expr: decl_ident.into(),
-                },
+                Stmt::Decl(with_export_span(export_decl.decl.clone(), export_decl.span)),
             ))
-        } else {
-            None
         }
     }
 
     /// Converts an export default declaration to an `Exp`.
+    ///
+    /// Anonymous declarations are bound to the anonymous identifier (`__default`)
+    /// and keep their `name` as `"default"`.
+    ///
+    /// - `Decl`: Declaration to replace the original export default declaration
+    /// - `Option<Stmt>`: Statement to run before the module body (name of the hoisted function)
     pub fn export_default_decl_as_exp(
         export_default_decl: &ExportDefaultDecl,
-    ) -> Option<(Exp, Decl, ExpBinding)> {
-        if let Some(decl) = match &export_default_decl.decl {
-            DefaultDecl::Class(class_expr) => {
-                // Clone class identifier if has one or create a new anonymous identifier for bind
-                let class_ident = class_expr
-                    .ident
-                    .clone()
-                    .unwrap_or_else(|| anonymous_default_binding_ident());
-
-                Some(Decl::Class(ClassDecl {
+        idents: &Idents,
+    ) -> Option<(Exp, Decl, Option<Stmt>)> {
+        let (decl_ident, decl, hoisted_stmt) = match &export_default_decl.decl {
+            // ```js
+            // // Given code
+            // export default class Foo {}
+            //
+            // // Returns
+            // class Foo {}
+            // ```
+            DefaultDecl::Class(ClassExpr {
+                ident: Some(class_ident),
+                class,
+            }) => (
+                class_ident.clone(),
+                Decl::Class(ClassDecl {
                     ident: class_ident.clone(),
-                    class: class_expr.class.clone(),
+                    class: class.clone(),
                     declare: false,
-                }))
+                }),
+                None,
+            ),
+            // ```js
+            // // Given code
+            // export default class {}
+            //
+            // // Returns
+            // const __default = Object.defineProperty(class {}, "name", { ... });
+            // ```
+            DefaultDecl::Class(class_expr) => {
+                let binding_ident = idents.anonymous_default_binding_ident();
+                let class_expr = Expr::Class(class_expr.clone());
+
+                (
+                    binding_ident.clone(),
+                    VarDecl {
+                        decls: vec![var_declarator(
+                            binding_ident.into(),
+                            Some(Box::new(to_default_named_expr(class_expr))),
+                        )],
+                        kind: VarDeclKind::Const,
+                        ..Default::default()
+                    }
+                    .into(),
+                    None,
+                )
             }
+            // ```js
+            // // Given code
+            // export default function foo() {}
+            // export default function () {}
+            //
+            // // Returns
+            // function foo() {}
+            // function __default() {} // Object.defineProperty(__default, "name", { ... });
+            // ```
             DefaultDecl::Fn(fn_expr) => {
-                // Clone function identifier if has one or create a new anonymous identifier for bind
-                let fn_ident = fn_expr
-                    .ident
-                    .clone()
-                    .unwrap_or_else(|| anonymous_default_binding_ident());
-
-                Some(Decl::Fn(FnDecl {
-                    ident: fn_ident,
-                    function: fn_expr.function.clone(),
-                    declare: false,
-                }))
+                let (fn_ident, hoisted_stmt) = match &fn_expr.ident {
+                    Some(fn_ident) => (fn_ident.clone(), None),
+                    None => {
+                        let binding_ident = idents.anonymous_default_binding_ident();
+                        let stmt = default_name_call(binding_ident.clone().into()).into_stmt();
+
+                        (binding_ident, Some(stmt))
+                    }
+                };
+
+                (
+                    fn_ident.clone(),
+                    Decl::Fn(FnDecl {
+                        ident: fn_ident,
+                        function: fn_expr.function.clone(),
+                        declare: false,
+                    }),
+                    hoisted_stmt,
+                )
             }
             // Ignore TypeScript interface declaration
-            DefaultDecl::TsInterfaceDecl(_) => None,
-        } {
-            let decl_ident = match &decl {
-                Decl::Class(class_decl) => class_decl.ident.clone(),
-                Decl::Fn(fn_decl) => fn_decl.ident.clone(),
-                _ => unreachable!(),
-            };
-            let exp_binding_ident = exp_binding_ident();
-            let exp = Exp::Base(BaseExp::new(vec![ExpMember::new(
-                exp_binding_ident.clone(),
-                "default".into(),
-            )]));
+            DefaultDecl::TsInterfaceDecl(_) => return None,
+        };
 
-            Some((
-                exp,
-                // Keep the original export default declaration
-                decl,
-                // Create binding to reference the export declaration's identifier
-                //
-                // ```js
-                // // Given code
-                // export default function() {} // No identifier. It will be created an anonymous identifier for binding
-                // ```
-                // - binding_ident: __x
-                // - expr: __default (decl_ident)
-                ExpBinding {
-                    binding_ident: exp_binding_ident,
-                    expr: decl_ident.into(),
-                },
-            ))
-        } else {
-            None
-        }
+        let exp = Exp::Base(BaseExp::new(vec![ExpMember::new(
+            decl_ident,
+            "default".into(),
+        )]));
+
+        Some((
+            exp,
+            with_export_span(decl, export_default_decl.span),
+            hoisted_stmt,
+        ))
     }
 
     /// Converts an export default expression to an `Exp`.
     pub fn export_default_expr_as_exp(
         export_default_expr: &mut ExportDefaultExpr,
-    ) -> (Exp, Stmt, ExpBinding) {
-        let binding_ident = anonymous_default_binding_ident();
-        let exp_binding_ident = exp_binding_ident();
+        idents: &Idents,
+    ) -> (Exp, Stmt) {
+        let binding_ident = idents.anonymous_default_binding_ident();
         let exp = Exp::Base(BaseExp::new(vec![ExpMember::new(
-            exp_binding_ident.clone(),
+            binding_ident.clone(),
             "default".into(),
         )]));
 
@@ -549,16 +852,21 @@ pub mod ast {
         // ```js
         // // Given code
         // export default foo;
+        // export default () => {};
         //
         // // Returns
-        // var __default = foo; // Use `__default` as binding identifier
+        // const __default = foo; // Use `__default` as binding identifier
+        // const __default = Object.defineProperty(() => {}, "name", { ... });
         // ```
         let default_var_decl = VarDecl {
             decls: vec![var_declarator(
-                binding_ident.clone().into(),
-                Some(Box::new(*export_default_expr.expr.clone())),
+                binding_ident.into(),
+                Some(Box::new(to_default_named_expr(
+                    *export_default_expr.expr.clone(),
+                ))),
             )],
             kind: VarDeclKind::Const,
+            span: export_default_expr.span,
             ..Default::default()
         };
 
@@ -566,50 +874,70 @@ pub mod ast {
             exp,
             // Replace the original export default expression with a variable declaration
             default_var_decl.into(),
-            // Create binding to reference the default export expression
-            //
-            // ```js
-            // // Given code
-            // // export default foo;
-            // var __default = foo;
-            // ```
-            // - binding_ident: __x
-            // - expr: __default (binding_ident)
-            ExpBinding {
-                binding_ident: exp_binding_ident,
-                expr: binding_ident.into(),
-            },
         )
     }
 
+    /// Wraps the anonymous function definition to keep its name as `"default"`.
+    ///
+    /// Otherwise, the name would be inferred from the binding identifier (`__default`).
+    ///
+    /// ```js
+    /// // Code
+    /// () => {}; // Object.defineProperty(() => {}, "name", { ... });
+    /// function () {}; // Object.defineProperty(function () {}, "name", { ... });
+    /// class {}; // Object.defineProperty(class {}, "name", { ... });
+    /// class { static name = 'foo' }; // Not wrapped
+    /// foo; // Not wrapped
+    /// ```
+    pub fn to_default_named_expr(expr: Expr) -> Expr {
+        if is_anonymous_fn_def(&expr) {
+            default_name_call(expr)
+        } else {
+            expr
+        }
+    }
+
+    /// Checks whether it is an anonymous function definition
+    /// that is named by the binding (excluding classes with own static `name` member).
+    fn is_anonymous_fn_def(expr: &Expr) -> bool {
+        match expr {
+            Expr::Paren(ParenExpr { expr, .. }) => is_anonymous_fn_def(expr),
+            Expr::Arrow(_) | Expr::Fn(FnExpr { ident: None, .. }) => true,
+            Expr::Class(ClassExpr { ident: None, class }) => {
+                !class.body.iter().any(|member| match member {
+                    ClassMember::Method(ClassMethod { key, is_static, .. })
+                    | ClassMember::ClassProp(ClassProp { key, is_static, .. }) => {
+                        *is_static && key.as_ident().is_some_and(|key| key.sym == "name")
+                    }
+                    _ => false,
+                })
+            }
+            _ => false,
+        }
+    }
+
     /// Converts an export named declaration to an `Exp`.
+    ///
+    /// `to_src` returns the source of the re-export (eg. mapped by `paths`).
     pub fn export_named_as_exp(
         export_named: &NamedExport,
-        paths: &Option<AHashMap<String, String>>,
-    ) -> Option<(Exp, Vec<ExpBinding>)> {
-        let mut exp_bindings: Vec<ExpBinding> = Vec::new();
-
+        to_src: impl FnOnce(String) -> String,
+    ) -> Option<Exp> {
         // If namespace export, it always has one specifier
-        if let Some(specifier) = export_named.specifiers.get(0) {
+        if let Some(specifier) = export_named.specifiers.first() {
             if specifier.is_namespace() {
                 let src = export_named.src.as_ref().unwrap().clone().value.to_string();
                 let ns = specifier.as_namespace().unwrap();
-                let ident = match &ns.name {
-                    ModuleExportName::Ident(ident) => ident.clone(),
-                    ModuleExportName::Str(str) => {
-                        Ident::new(str.value.clone(), DUMMY_SP, SyntaxContext::default())
-                    }
-                };
 
-                return Some((
-                    Exp::ReExportAll(ReExportAllExp::alias(to_mapped_src(&src, paths), ident)),
-                    exp_bindings,
-                ));
+                return Some(Exp::ReExportAll(ReExportAllExp::alias(
+                    to_src(src),
+                    to_export_name(&ns.name),
+                )));
             }
         }
 
         // Otherwise, it has multiple specifiers (Non-namespace export)
-        let members = export_named
+        let specs = export_named
             .specifiers
             .iter()
             .filter_map(|spec| match spec {
@@ -618,98 +946,129 @@ pub mod ast {
                 // ```js
                 // export value from 'src';
                 // ```
-                ExportSpecifier::Default(default) => {
-                    let exp_binding_ident = exp_binding_ident();
-
-                    exp_bindings.push(ExpBinding {
-                        binding_ident: exp_binding_ident.clone(),
-                        expr: Expr::from(default.exported.clone()),
-                    });
-
-                    Some(ExpMember::new(exp_binding_ident, "default".into()))
-                }
+                ExportSpecifier::Default(default) => Some((
+                    ModuleExportName::Ident(default.exported.clone()),
+                    String::from("default"),
+                )),
                 // Named export
                 //
                 // ```js
                 // // Named export
                 // export { foo, bar as baz };
                 // export { value as default };
+                // export { value as "string name" };
                 //
                 // // Re-export
                 // export { foo, bar as baz } from 'src';
                 // export { value as default } from 'src';
+                // export { "string name" as foo } from 'src';
                 // ```
                 ExportSpecifier::Named(ExportNamedSpecifier {
                     orig,
                     exported,
                     is_type_only: false,
                     ..
-                }) => {
-                    let exp_binding_ident = exp_binding_ident();
-                    let exported_ident = match orig {
-                        ModuleExportName::Ident(ident) => ident.clone(),
-                        ModuleExportName::Str(str) => {
-                            Ident::new(str.value.clone(), DUMMY_SP, SyntaxContext::default())
-                        }
-                    };
-                    let name = exported.as_ref().map_or_else(
-                        || exported_ident.sym.as_str().to_string(),
-                        |name| match name {
-                            ModuleExportName::Ident(ident) => ident.sym.as_str().to_string(),
-                            ModuleExportName::Str(str) => str.value.to_string(),
-                        },
-                    );
-
-                    if export_named.src.is_none() {
-                        exp_bindings.push(ExpBinding {
-                            binding_ident: exp_binding_ident.clone(),
-                            expr: Expr::from(exported_ident),
-                        });
-                        Some(ExpMember::new(exp_binding_ident, name))
-                    } else {
-                        Some(ExpMember::new(exported_ident, name))
-                    }
-                }
+                }) => Some((
+                    orig.clone(),
+                    to_export_name(exported.as_ref().unwrap_or(orig)),
+                )),
                 // Namespace export is already handled from the above condition
                 ExportSpecifier::Namespace(_) => unreachable!(),
                 _ => None,
-            })
-            .collect::<Vec<ExpMember>>();
+            });
 
-        // If there are no members, return None
-        if members.is_empty() {
-            None
-        } else {
-            Some((
-                if export_named.src.is_none() {
-                    // Plain named export
-                    Exp::Base(BaseExp::new(members))
-                } else {
-                    // Named re-export
-                    let src = export_named.src.as_ref().unwrap().clone().value.to_string();
+        match &export_named.src {
+            // Plain named export (local bindings are always identifiers)
+            None => {
+                let members = specs
+                    .filter_map(|(orig, name)| match orig {
+                        ModuleExportName::Ident(ident) => Some(ExpMember::new(ident, name)),
+                        ModuleExportName::Str(_) => None,
+                    })
+                    .collect::<Vec<ExpMember>>();
+
+                (!members.is_empty()).then(|| Exp::Base(BaseExp::new(members)))
+            }
+            // Named re-export
+            Some(src) => {
+                let members = specs
+                    .map(|(orig, name)| ReExpMember::new(to_export_name(&orig), name))
+                    .collect::<Vec<ReExpMember>>();
+
+                (!members.is_empty()).then(|| {
                     Exp::ReExportNamed(ReExportNamedExp {
-                        src: to_mapped_src(&src, paths),
+                        src: to_src(src.value.to_string()),
                         members,
                     })
-                },
-                exp_bindings,
-            ))
+                })
+            }
+        }
+    }
+
+    /// Returns the export name as a string.
+    ///
+    /// ```js
+    /// export { foo as bar }; // "bar"
+    /// export { foo as "string name" }; // "string name"
+    /// ```
+    pub fn to_export_name(name: &ModuleExportName) -> String {
+        match name {
+            ModuleExportName::Ident(ident) => ident.sym.to_string(),
+            ModuleExportName::Str(str) => str.value.to_string(),
         }
     }
 
+    /// Returns the module export name of the given name.
+    ///
+    /// Names that are not valid identifier names are represented as string literals.
+    ///
+    /// ```js
+    /// export { foo as bar }; // Ident
+    /// export { foo as "string name" }; // Str
+    /// ```
+    pub fn to_module_export_name(name: &str) -> ModuleExportName {
+        if is_ident_name(name) {
+            ModuleExportName::Ident(Ident::from(name))
+        } else {
+            ModuleExportName::Str(name.into())
+        }
+    }
+
+    /// Returns a member expression of the object by the given name.
+    ///
+    /// ```js
+    /// obj.foo;
+    /// obj["string name"];
+    /// ```
+    pub fn member_by_name(obj: Ident, name: &str) -> Expr {
+        let prop = if is_ident_name(name) {
+            MemberProp::Ident(IdentName::new(name.into(), DUMMY_SP))
+        } else {
+            MemberProp::Computed(ComputedPropName {
+                expr: Box::new(str_lit(name).into()),
+                span: DUMMY_SP,
+            })
+        };
+
+        Expr::Member(MemberExpr {
+            obj: Box::new(obj.into()),
+            prop,
+            span: DUMMY_SP,
+        })
+    }
+
     /// Converts an export all declaration to an `Exp`.
-    pub fn export_all_as_exp(
-        export_all: &ExportAll,
-        paths: &Option<AHashMap<String, String>>,
-    ) -> Exp {
+    ///
+    /// `to_src` returns the source of the re-export (eg. mapped by `paths`).
+    pub fn export_all_as_exp(export_all: &ExportAll, to_src: impl FnOnce(String) -> String) -> Exp {
         let src = export_all.src.as_ref().clone().value.to_string();
-        Exp::ReExportAll(ReExportAllExp::new(to_mapped_src(&src, paths)))
+        Exp::ReExportAll(ReExportAllExp::new(to_src(src)))
     }
 }
 
 pub mod presets {
     use swc_core::{
-        common::DUMMY_SP,
+        common::{SyntaxContext, DUMMY_SP},
         ecma::{
             ast::*,
             utils::{member_expr, quote_ident, ExprFactory},
@@ -724,7 +1083,7 @@ pub mod presets {
     /// // Code
     /// global.__modules.register(id);
     /// ```
-    pub fn register_call(id: &String) -> Expr {
+    pub fn register_call(id: &str) -> Expr {
         member_expr!(Default::default(), DUMMY_SP, global.__modules.register)
             .as_call(DUMMY_SP, vec![str_lit(id).as_arg()])
     }
@@ -740,6 +1099,16 @@ pub mod presets {
             .as_call(DUMMY_SP, vec![src.as_arg()])
     }
 
+    /// Returns a global module's require function expression.
+    ///
+    /// ```js
+    /// // Code
+    /// global.__modules.require;
+    /// ```
+    pub fn require_fn() -> Expr {
+        member_expr!(Default::default(), DUMMY_SP, global.__modules.require).into()
+    }
+
     /// Returns a global module's import call expression.
     ///
     /// This is same as `ctx_ident.require(src)` but it returns a `Promise`.
@@ -753,15 +1122,142 @@ pub mod presets {
             .as_call(DUMMY_SP, vec![src.as_arg()])
     }
 
-    /// Converts to require statement
+    /// Returns a SystemJS register call expression.
     ///
     /// ```js
-    /// const mod_ident = global.__modules.require('src');
+    /// // Code
+    /// System.register(id, [src1, src2], declare);
     /// ```
-    pub fn to_require_stmt(mod_ident: Ident, src: String) -> Stmt {
-        require_call(src.into())
-            .into_var_decl(VarDeclKind::Const, mod_ident.into())
-            .into()
+    pub fn system_register_call(id: &str, srcs: Vec<String>, declare: Expr) -> Expr {
+        let deps = ArrayLit {
+            elems: srcs
+                .into_iter()
+                .map(|src| Some(str_lit(&src).as_arg()))
+                .collect(),
+            span: DUMMY_SP,
+        };
+
+        member_expr!(Default::default(), DUMMY_SP, System.register).as_call(
+            DUMMY_SP,
+            vec![str_lit(id).as_arg(), deps.as_arg(), declare.as_arg()],
+        )
+    }
+
+    /// Returns a SystemJS export call expression.
+    ///
+    /// `_export(name, value)` returns the value, so it can wrap the assignments.
+    ///
+    /// ```js
+    /// // Code
+    /// _export(name, value);
+    /// ```
+    pub fn system_export_call(export_ident: &Ident, name: &str, value: Expr) -> Expr {
+        export_ident
+            .clone()
+            .as_call(DUMMY_SP, vec![str_lit(name).as_arg(), value.as_arg()])
+    }
+
+    /// Returns a SystemJS dynamic import call expression.
+    ///
+    /// ```js
+    /// // Code
+    /// _context.import(src);
+    /// ```
+    pub fn system_import_call(context_ident: &Ident, src: Lit) -> Expr {
+        context_ident
+            .clone()
+            .make_member(quote_ident!("import"))
+            .as_call(DUMMY_SP, vec![src.as_arg()])
+    }
+
+    /// Returns a member expression that references the context's module meta.
+    ///
+    /// ```js
+    /// // Code
+    /// ctx_ident.meta;
+    /// ```
+    pub fn module_meta_member(ctx_ident: &Ident) -> MemberExpr {
+        ctx_ident.clone().make_member(quote_ident!("meta"))
+    }
+
+    /// Returns a global module's URL call expression.
+    ///
+    /// The registry returns the URL of the worker or asset that is registered with the source.
+    ///
+    /// ```js
+    /// // Code
+    /// global.__modules.url(src);
+    /// ```
+    pub fn url_call(src: Lit) -> Expr {
+        member_expr!(Default::default(), DUMMY_SP, global.__modules.url)
+            .as_call(DUMMY_SP, vec![src.as_arg()])
+    }
+
+    /// Returns an expression of the dotted path.
+    ///
+    /// ```js
+    /// // Code
+    /// global.__host; // "global.__host"
+    /// ```
+    pub fn path_expr(path: &str) -> Expr {
+        let mut segments = path.split('.');
+        let root = Ident::new_no_ctxt(segments.next().unwrap_or_default().into(), DUMMY_SP);
+
+        segments.fold(root.into(), |obj, prop| {
+            obj.make_member(IdentName::new(prop.into(), DUMMY_SP))
+                .into()
+        })
+    }
+
+    /// Returns a host accessor's require call expression (external dependencies).
+    ///
+    /// ```js
+    /// // Code
+    /// accessor.require(src);
+    /// ```
+    pub fn host_require_call(accessor: &str, src: Lit) -> Expr {
+        path_expr(accessor)
+            .make_member(quote_ident!("require"))
+            .as_call(DUMMY_SP, vec![src.as_arg()])
+    }
+
+    /// Returns a host accessor's import call expression (external dependencies).
+    ///
+    /// ```js
+    /// // Code
+    /// accessor.import(src);
+    /// ```
+    pub fn host_import_call(accessor: &str, src: Lit) -> Expr {
+        path_expr(accessor)
+            .make_member(quote_ident!("import"))
+            .as_call(DUMMY_SP, vec![src.as_arg()])
+    }
+
+    /// Returns a call expression that defines the `name` property as `"default"`.
+    ///
+    /// ```js
+    /// // Code
+    /// Object.defineProperty(expr, "name", { value: "default", configurable: true });
+    /// ```
+    pub fn default_name_call(expr: Expr) -> Expr {
+        member_expr!(Default::default(), DUMMY_SP, Object.defineProperty).as_call(
+            DUMMY_SP,
+            vec![
+                expr.as_arg(),
+                str_lit("name").as_arg(),
+                obj_lit_expr(vec![
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident("value".into()),
+                        value: Box::new(str_lit("default").into()),
+                    }))),
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident("configurable".into()),
+                        value: Box::new(true.into()),
+                    }))),
+                ])
+                .as_arg(),
+            ],
+        )
     }
 
     /// Returns a global module's exports call expression.
@@ -769,16 +1265,37 @@ pub mod presets {
     /// ```js
     /// // Code
     /// ctx_ident.exports(function () {
-    ///   return expr;
+    ///   return definitions;
     /// });
     /// ```
-    pub fn exports_call(ctx_ident: &Ident, exp_props: Vec<PropOrSpread>) -> Expr {
+    pub fn exports_call(ctx_ident: &Ident, definitions: Expr) -> Expr {
+        exports_call_with_sources(ctx_ident, definitions, Vec::new())
+    }
+
+    /// Returns a global module's exports call expression with the namespace export sources.
+    ///
+    /// The namespace exports of the sources are registered after the definitions.
+    ///
+    /// ```js
+    /// // Code
+    /// ctx_ident.exports(function () {
+    ///   return definitions;
+    /// }, source1, source2);
+    /// ```
+    pub fn exports_call_with_sources(
+        ctx_ident: &Ident,
+        definitions: Expr,
+        sources: Vec<Expr>,
+    ) -> Expr {
         ctx_ident
             .clone()
             .make_member(quote_ident!("exports"))
             .as_call(
                 DUMMY_SP,
-                vec![obj_lit_expr(exp_props).into_lazy_fn(vec![]).as_arg()],
+                [definitions.into_lazy_fn(vec![]).as_arg()]
+                    .into_iter()
+                    .chain(sources.into_iter().map(ExprOrSpread::from))
+                    .collect(),
             )
     }
 
@@ -801,39 +1318,37 @@ pub mod presets {
             })
     }
 
-    /// Returns a new expression that binds the CommonJS module export statement.
+    /// Returns a new expression that binds the CommonJS module exports.
     ///
     /// ```js
     /// // Code
-    /// ctx_ident.module.exports.name = expr;
+    /// ctx_ident.module.exports = expr;
     /// ```
-    pub fn assign_cjs_module_expr(ctx_ident: &Ident, expr: Expr, name: Option<Expr>) -> Expr {
-        let ctx_module_member = module_exports_member(ctx_ident);
+    pub fn assign_cjs_module_expr(ctx_ident: &Ident, expr: Expr) -> Expr {
+        assign_member(module_exports_member(ctx_ident), expr)
+    }
 
-        assign_member(
-            match name {
-                // Named export
-                //
-                // ```js
-                // module.exports.foo = expr;
-                // ```
-                Some(name_expr) => match name_expr {
-                    Expr::Lit(Lit::Str(str_lit)) => ctx_module_member.make_member(IdentName {
-                        sym: str_lit.value.clone().into(),
-                        ..Default::default()
-                    }),
-                    _ => ctx_module_member.computed_member(name_expr.clone()),
-                },
-                // Main export
-                //
-                // ```js
-                // module.exports = expr;
-                // ```
-                None => ctx_module_member,
-            },
-            expr,
+    /// Returns a new statement that aliases the context's module exports
+    /// to the CommonJS module exports object.
+    ///
+    /// ```js
+    /// // Code
+    /// ctx_ident.module.exports = module.exports;
+    /// ```
+    pub fn alias_cjs_module_exports_stmt(
+        ctx_ident: &Ident,
+        unresolved_ctxt: SyntaxContext,
+    ) -> Stmt {
+        assign_cjs_module_expr(
+            ctx_ident,
+            Ident::new("module".into(), DUMMY_SP, unresolved_ctxt)
+                .make_member(IdentName {
+                    sym: "exports".into(),
+                    ..Default::default()
+                })
+                .into(),
         )
-        .into()
+        .into_stmt()
     }
 
     /// Returns a named export statement based on given export specifiers.
@@ -0,0 +1,60 @@
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{transform, ModuleKind, ModuleSource, Options};
+
+#[test]
+fn collect_amd_deps_with_paths() {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./foo"), String::from("1001"));
+    paths.insert(String::from("./bar"), String::from("1002"));
+
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "define(['./foo', 'require', 'exports'], function (foo, require, exports) {\n  exports.bar = require('./bar');\n});",
+        ),
+        true,
+        &Some(paths),
+        &Options::default(),
+    )
+    .unwrap();
+
+    let mut deps = output.deps.clone();
+    deps.sort();
+
+    assert_eq!(output.kind, ModuleKind::Cjs);
+    assert_eq!(deps, vec!["1001", "1002"]);
+    assert!(output.code.contains("global.__modules.require(\"1001\")"));
+    assert!(output.code.contains("global.__modules.require(\"1002\")"));
+}
+
+#[test]
+fn ignore_local_define() {
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "function load(define) {\n  define(['./foo'], function (foo) {});\n}",
+        ),
+        true,
+        &None,
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert!(output.deps.is_empty());
+    assert!(!output.code.contains("module.exports"));
+}
+
+#[test]
+fn report_invalid_define_once() {
+    let error = transform(
+        &ModuleSource::new("1000", "index.js", "define([dep], function (foo) {});"),
+        true,
+        &None,
+        &Options::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(error.messages, vec!["invalid define dependencies"]);
+}
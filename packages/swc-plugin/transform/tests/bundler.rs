@@ -0,0 +1,115 @@
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{bundle, ModuleSource, Options};
+
+fn sources() -> (Vec<ModuleSource>, AHashMap<String, String>) {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./math"), String::from("1001"));
+    paths.insert(String::from("./legacy"), String::from("1002"));
+
+    (
+        vec![
+            ModuleSource::new(
+                "1000",
+                "index.js",
+                "import { add } from './math';\nconst legacy = require('./legacy');\nbridge(add(1, 2), legacy.name);",
+            ),
+            ModuleSource::new(
+                "1001",
+                "math.js",
+                "export function add(a, b) { return a + b; }",
+            ),
+            ModuleSource::new("1002", "legacy.js", "module.exports = { name: 'legacy' };"),
+        ],
+        paths,
+    )
+}
+
+#[test]
+fn bundle_modules_into_factories() {
+    let (sources, paths) = sources();
+    let bundle = bundle(
+        &sources,
+        &[String::from("1000")],
+        &Some(paths),
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(bundle.ids, vec!["1001", "1002", "1000"]);
+    assert!(bundle.code.starts_with(
+        "(function (global) {\nif (global.__modules == null) {\n// Generated from `@global-modules/runtime`"
+    ));
+    assert!(bundle
+        .code
+        .ends_with("global.__modules.require(\"1000\");\n})(typeof globalThis !== 'undefined' ? globalThis : this);\n"));
+
+    let factory_positions = bundle
+        .ids
+        .iter()
+        .map(|id| {
+            bundle
+                .code
+                .find(&format!(
+                    "__define(\"{}\", function (module, exports) {{\nconst __context = global.__modules.register(\"{}\");",
+                    id, id
+                ))
+                .unwrap_or_else(|| panic!("factory of '{}' not found", id))
+        })
+        .collect::<Vec<usize>>();
+
+    assert!(factory_positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn register_exports_before_requiring_deps() {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./a"), String::from("a"));
+    paths.insert(String::from("./b"), String::from("b"));
+
+    let bundle = bundle(
+        &[
+            ModuleSource::new(
+                "a",
+                "a.js",
+                "import { greet } from './b';\nexport function hello() { return 'hello'; }\ngreet();",
+            ),
+            ModuleSource::new(
+                "b",
+                "b.js",
+                "import { hello } from './a';\nexport function greet() { return hello(); }",
+            ),
+        ],
+        &[String::from("a")],
+        &Some(paths),
+        &Options::default(),
+    )
+    .unwrap();
+
+    // Circular importer `b` can access the hoisted `hello` while `a` is requiring `b`
+    let factory = &bundle.code[bundle.code.find("__define(\"a\"").unwrap()..];
+    let exports_pos = factory.find("\"hello\": hello").unwrap();
+    let require_pos = factory.find("global.__modules.require(\"b\")").unwrap();
+
+    assert!(exports_pos < require_pos);
+}
+
+#[test]
+fn reject_top_level_await() {
+    let error = bundle(
+        &[ModuleSource::new(
+            "1000",
+            "index.js",
+            "const config = await fetch('/config');\nexport default config;",
+        )],
+        &[String::from("1000")],
+        &None,
+        &Options::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(error.filename, "index.js");
+    assert_eq!(
+        error.messages,
+        vec!["top-level await is not supported in bundled modules"]
+    );
+}
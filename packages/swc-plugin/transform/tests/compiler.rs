@@ -0,0 +1,27 @@
+use swc_global_modules::{transform, ModuleSource, Options};
+
+#[test]
+fn preserve_comments_and_directives() {
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "/*! license: MIT */\n'use client';\nimport { foo } from './foo';\n\n/** Docs of `bar`. */\nexport const bar = foo;",
+        ),
+        true,
+        &None,
+        &Options::default(),
+    )
+    .unwrap();
+
+    let license_pos = output.code.find("/*! license: MIT */").unwrap();
+    let directive_pos = output.code.find("'use client';").unwrap();
+    let register_pos = output
+        .code
+        .find("global.__modules.register(\"1000\")")
+        .unwrap();
+
+    assert!(license_pos < directive_pos);
+    assert!(directive_pos < register_pos);
+    assert!(output.code.contains("/** Docs of `bar`. */"));
+}
@@ -0,0 +1,161 @@
+use regex::Regex;
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{bundle, transform, Externals, ModuleSource, Options};
+
+fn externals_options(accessor: Option<&str>) -> Options {
+    Options {
+        externals: Externals {
+            names: vec![String::from("fs")],
+            patterns: vec![Regex::new("^node:").unwrap()],
+            accessor: accessor.map(String::from),
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn exclude_externals_from_deps() {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./foo"), String::from("1001"));
+    paths.insert(String::from("fs"), String::from("1002"));
+
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "import fs from 'fs';\nimport { join } from 'node:path';\nimport { foo } from './foo';\nexport * from 'node:events';\nconsole.log(fs, join, foo);",
+        ),
+        true,
+        &Some(paths),
+        &externals_options(None),
+    )
+    .unwrap();
+
+    assert_eq!(output.deps, vec!["1001"]);
+    assert_eq!(output.externals, vec!["fs", "node:path", "node:events"]);
+    assert!(output.code.contains("import fs from 'fs';"));
+    assert!(output
+        .code
+        .contains("import * as __mod from \"node:events\";"));
+    assert!(!output.code.contains("global.__modules.require(\"fs\")"));
+}
+
+#[test]
+fn keep_external_require_calls() {
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "const fs = require('fs');\nconst foo = require('./foo');\nmodule.exports = { fs, foo };",
+        ),
+        true,
+        &None,
+        &externals_options(None),
+    )
+    .unwrap();
+
+    assert_eq!(output.deps, vec!["./foo"]);
+    assert_eq!(output.externals, vec!["fs"]);
+    assert!(output.code.contains("require('fs')"));
+    assert!(output.code.contains("global.__modules.require(\"./foo\")"));
+}
+
+#[test]
+fn map_externals_to_host_accessor() {
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "import { readFile } from 'node:fs';\nconst path = require('node:path');\nconst util = await import('node:util');\nconsole.log(readFile, path, util);",
+        ),
+        true,
+        &None,
+        &externals_options(Some("global.__host")),
+    )
+    .unwrap();
+
+    assert!(output.deps.is_empty());
+    assert_eq!(output.externals, vec!["node:fs", "node:path", "node:util"]);
+    assert!(output
+        .code
+        .contains("const { readFile } = global.__host.require(\"node:fs\");"));
+    assert!(output
+        .code
+        .contains("const path = global.__host.require(\"node:path\");"));
+    assert!(output
+        .code
+        .contains("const util = await global.__host.import(\"node:util\");"));
+}
+
+#[test]
+fn bundle_externals() {
+    let output = bundle(
+        &[
+            ModuleSource::new(
+                "1000",
+                "index.js",
+                "import { readFile } from 'node:fs';\nimport { foo } from './foo';\nconsole.log(readFile, foo);",
+            ),
+            ModuleSource::new(
+                "./foo",
+                "foo.js",
+                "import fs from 'fs';\nexport const foo = fs;",
+            ),
+        ],
+        &[String::from("1000")],
+        &None,
+        &externals_options(Some("global.__host")),
+    )
+    .unwrap();
+
+    assert_eq!(output.ids, vec!["./foo", "1000"]);
+    assert_eq!(output.externals, vec!["fs", "node:fs"]);
+    assert!(output.code.contains("global.__host.require(\"fs\")"));
+    assert!(!output.code.contains("import fs"));
+}
+
+#[test]
+fn reject_bundle_externals_without_accessor() {
+    let error = bundle(
+        &[
+            ModuleSource::new(
+                "1000",
+                "index.js",
+                "import { foo } from './foo';\nconsole.log(foo);",
+            ),
+            ModuleSource::new(
+                "./foo",
+                "foo.js",
+                "import fs from 'fs';\nexport const foo = fs;",
+            ),
+        ],
+        &[String::from("1000")],
+        &None,
+        &externals_options(None),
+    )
+    .unwrap_err();
+
+    assert_eq!(error.filename, "foo.js");
+    assert_eq!(
+        error.messages,
+        vec!["external dependency 'fs' requires `externals.accessor` in bundled modules"]
+    );
+}
+
+#[test]
+fn reject_invalid_accessor() {
+    for accessor in ["", "a..b", "1x", "global.__host."] {
+        let error = transform(
+            &ModuleSource::new("1000", "index.js", "import fs from 'fs';\nconsole.log(fs);"),
+            true,
+            &None,
+            &externals_options(Some(accessor)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.messages,
+            vec![format!("invalid accessor of `externals`: '{}'", accessor)]
+        );
+    }
+}
@@ -1,16 +1,23 @@
 use std::path::PathBuf;
 
+use regex::Regex;
 use swc_core::{
     common::{collections::AHashMap, Mark, SyntaxContext},
     ecma::{ast::Pass, transforms::base::resolver, visit::VisitMut},
 };
 use swc_ecma_parser::{Syntax, TsSyntax};
 use swc_ecma_transforms_testing::test_fixture;
-use swc_global_modules::global_modules;
+use swc_global_modules::{
+    global_modules, Externals, Identifiers, Options, OutputFormat, RegisterPlacement,
+};
 
 const MODULE_ID: &str = "1000";
 
-fn tr(runtime: bool, paths: Option<AHashMap<String, String>>) -> impl VisitMut + Pass {
+fn tr(
+    runtime: bool,
+    paths: Option<AHashMap<String, String>>,
+    options: Options,
+) -> impl VisitMut + Pass {
     let unresolved_mark = Mark::new();
     let top_level_mark = Mark::new();
 
@@ -20,6 +27,7 @@ fn tr(runtime: bool, paths: Option<AHashMap<String, String>>) -> impl VisitMut +
             String::from(MODULE_ID),
             runtime,
             paths,
+            options,
             SyntaxContext::empty().apply_mark(unresolved_mark),
         ),
     )
@@ -36,7 +44,7 @@ fn bundle_fixture(input: PathBuf) {
             tsx: filename.ends_with(".tsx"),
             ..Default::default()
         }),
-        &|_| tr(runtime, None),
+        &|_| tr(runtime, None, Options::default()),
         &input,
         &output,
         Default::default(),
@@ -52,9 +60,10 @@ fn runtime_fixture(input: PathBuf) {
     test_fixture(
         Syntax::Typescript(TsSyntax {
             tsx: filename.ends_with(".tsx"),
+            decorators: true,
             ..Default::default()
         }),
-        &|_| tr(runtime, None),
+        &|_| tr(runtime, None, Options::default()),
         &input,
         &output,
         Default::default(),
@@ -83,13 +92,221 @@ fn paths_fixture(input: PathBuf) {
     paths.insert(String::from("./re-exp-3"), String::from("1012"));
     paths.insert(String::from("./re-exp-4"), String::from("1013"));
     paths.insert(String::from("./re-exp-5"), String::from("1014"));
+    paths.insert(String::from("./worker.js"), String::from("1015"));
+    paths.insert(String::from("./logo.png"), String::from("1016"));
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, Some(paths.clone()), Options::default()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/identifiers/**/input.js")]
+fn identifiers_fixture(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let runtime = true;
+    let options = Options {
+        identifiers: Identifiers {
+            context: String::from("__ctx"),
+            default: String::from("__def"),
+            module: String::from("__m"),
+        },
+        ..Default::default()
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, None, options.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/compact/bundle/**/input.js")]
+fn compact_bundle_fixture(input: PathBuf) {
+    compact_fixture(input, false);
+}
+
+#[testing::fixture("tests/fixture/compact/runtime/**/input.js")]
+fn compact_runtime_fixture(input: PathBuf) {
+    compact_fixture(input, true);
+}
+
+fn compact_fixture(input: PathBuf, runtime: bool) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let options = Options {
+        compact: true,
+        ..Default::default()
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, None, options.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/system/**/input.js")]
+fn system_fixture(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let runtime = true;
+    let options = Options {
+        format: OutputFormat::System,
+        ..Default::default()
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, None, options.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/externals/native/**/input.js")]
+fn externals_native_fixture(input: PathBuf) {
+    externals_fixture(input, None);
+}
+
+#[testing::fixture("tests/fixture/externals/host/**/input.js")]
+fn externals_host_fixture(input: PathBuf) {
+    externals_fixture(input, Some(String::from("global.__host")));
+}
+
+fn externals_fixture(input: PathBuf, accessor: Option<String>) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let runtime = true;
+    let options = Options {
+        externals: Externals {
+            names: vec![String::from("fs")],
+            patterns: vec![Regex::new("^node:").unwrap()],
+            accessor,
+        },
+        ..Default::default()
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, None, options.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/used_exports/bundle/**/input.js")]
+fn used_exports_bundle_fixture(input: PathBuf) {
+    used_exports_fixture(input, false);
+}
+
+#[testing::fixture("tests/fixture/used_exports/runtime/**/input.js")]
+fn used_exports_runtime_fixture(input: PathBuf) {
+    used_exports_fixture(input, true);
+}
+
+fn used_exports_fixture(input: PathBuf, runtime: bool) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let mut used_exports = AHashMap::default();
+    used_exports.insert(
+        String::from(MODULE_ID),
+        vec![String::from("foo"), String::from("qux")],
+    );
+    let options = Options {
+        used_exports,
+        ..Default::default()
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, None, options.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/register_placement/after_imports/**/input.js")]
+fn register_after_imports_fixture(input: PathBuf) {
+    register_placement_fixture(input, RegisterPlacement::AfterImports);
+}
+
+#[testing::fixture("tests/fixture/register_placement/before_imports/**/input.js")]
+fn register_before_imports_fixture(input: PathBuf) {
+    register_placement_fixture(input, RegisterPlacement::BeforeImports);
+}
+
+fn register_placement_fixture(input: PathBuf, register_placement: RegisterPlacement) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let runtime = false;
+    let options = Options {
+        register_placement,
+        ..Default::default()
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        }),
+        &|_| tr(runtime, None, options.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[testing::fixture("tests/fixture/unresolved/**/input.js")]
+fn unresolved_fixture(input: PathBuf) {
+    let filename = input.to_string_lossy();
+    let output = input.with_file_name("output.js");
+    let runtime = true;
 
+    // Without the `resolver` pass
     test_fixture(
         Syntax::Typescript(TsSyntax {
             tsx: filename.ends_with(".tsx"),
             ..Default::default()
         }),
-        &|_| tr(runtime, Some(paths.clone())),
+        &|_| {
+            global_modules(
+                String::from(MODULE_ID),
+                runtime,
+                None,
+                Options::default(),
+                SyntaxContext::empty(),
+            )
+        },
         &input,
         &output,
         Default::default(),
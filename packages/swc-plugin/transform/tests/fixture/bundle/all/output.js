@@ -9,14 +9,68 @@ import * as __mod2 from "./re-exp-3";
 import * as __mod3 from "./re-exp-4";
 import * as __mod4 from "./re-exp-5";
 const __context = global.__modules.register("1000");
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
+__context.exports(function() {
+    return {
+        get "variable" () {
+            return variable;
+        },
+        get "Class" () {
+            return Class;
+        },
+        "func": func,
+        "default": __default,
+        get "value2" () {
+            return value;
+        },
+        get "foo" () {
+            return foo;
+        },
+        get "foo2" () {
+            return foo2;
+        },
+        get "baz" () {
+            return baz;
+        },
+        get "baz2" () {
+            return baz;
+        },
+        get "rx0" () {
+            return __mod2.rx0;
+        },
+        get "rx1" () {
+            return __mod2.rx1;
+        },
+        get "rx2" () {
+            return __mod2.rx2;
+        },
+        get "rx4" () {
+            return __mod3.rx3;
+        },
+        get "rx5" () {
+            return __mod4.default;
+        }
+    };
+});
+__context.exports(function() {
+    return {
+        "rx": __mod1
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
 React.lazy(()=>import('./Component'));
 if (__DEV__) {
     require('./cjs-1');
 }
 const value = 'val';
-module.exports = __context.module.exports = 'cjs';
-module.exports.foo = __context.module.exports.foo = 2;
-Object.assign(module.exports = __context.module.exports, {
+module.exports = 'cjs';
+module.exports.foo = 2;
+Object.assign(module.exports, {
     bar: 1
 });
 const variable = 1;
@@ -30,31 +84,9 @@ function __default() {
         require('./cjs-3');
     };
 }
-__x = variable, __x1 = Class, __x2 = func, __x3 = __default, __x4 = value, __x5 = foo, __x6 = foo2, __x7 = baz, __x8 = baz;
-__context.exports(function() {
-    return {
-        "variable": __x,
-        "Class": __x1,
-        "func": __x2,
-        "default": __x3,
-        "value2": __x4,
-        "foo": __x5,
-        "foo2": __x6,
-        "baz": __x7,
-        "baz2": __x8,
-        ...__context.exports.ns(__mod),
-        "rx": __context.exports.ns(__mod1),
-        rx0: __mod2.rx0,
-        rx1: __mod2.rx1,
-        rx2: __mod2.rx2,
-        rx4: __mod3.rx3,
-        rx5: __mod4.default
-    };
-});
-var __x, __x1, __x2, __x3, __x4, __x5, __x6, __x7, __x8;
 export * from './re-exp';
 export * as rx from './re-exp-2';
 export { rx0, rx1, rx2 } from './re-exp-3';
 export { rx3 as rx4 } from './re-exp-4';
 export { default as rx5 } from './re-exp-5';
-export { __x as variable, __x1 as Class, __x2 as func, __x3 as default, __x4 as value2, __x5 as foo, __x6 as foo2, __x7 as baz, __x8 as baz2 };
+export { variable as variable, Class as Class, func as func, __default as default, value as value2, foo as foo, foo2 as foo2, baz as baz, baz as baz2 };
@@ -0,0 +1,6 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+module.exports = __context.module.exports = function(foo, exports1, require) {
+    exports1.foo = foo;
+    exports1.bar = require('./bar');
+}(require("./foo"), exports, require) ?? module.exports;
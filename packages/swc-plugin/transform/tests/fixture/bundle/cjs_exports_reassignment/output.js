@@ -0,0 +1,7 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+exports = module.exports = __context.module.exports = function main() {
+    return 'main';
+};
+exports.foo = 'foo';
+__context.module.exports = module.exports ||= {};
@@ -0,0 +1,10 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+this.foo = 'foo';
+exports.self = this;
+const arrow = ()=>this;
+function fn() {
+    return this;
+}
+module.exports.fn = fn;
+module.exports.arrow = arrow;
@@ -0,0 +1,19 @@
+/*! license: MIT */
+/** @jsx h */
+'use client';
+'use strict';
+import { h } from 'preact';
+import { foo } from './foo';
+
+/**
+ * Docs of `bar`.
+ */
+export const bar = foo;
+
+// Comment of `baz`
+export function baz() {
+  return h('div');
+}
+
+/** Docs of the default class. */
+export default class Qux {}
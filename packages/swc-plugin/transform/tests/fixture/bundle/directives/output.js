@@ -0,0 +1,26 @@
+/*! license: MIT */ /** @jsx h */ 'use client';
+'use strict';
+import { h } from 'preact';
+import { foo } from './foo';
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "bar" () {
+            return bar;
+        },
+        "baz": baz,
+        get "default" () {
+            return Qux;
+        }
+    };
+});
+/**
+ * Docs of `bar`.
+ */ const bar = foo;
+// Comment of `baz`
+function baz() {
+    return h('div');
+}
+/** Docs of the default class. */ class Qux {
+}
+export { bar as bar, baz as baz, Qux as default };
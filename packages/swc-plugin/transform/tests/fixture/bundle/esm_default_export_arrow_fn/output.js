@@ -0,0 +1,13 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __default;
+        }
+    };
+});
+const __default = Object.defineProperty(()=>{}, "name", {
+    value: "default",
+    configurable: true
+});
+export { __default as default };
@@ -1,11 +1,14 @@
 const __context = global.__modules.register("1000");
-class __default {
-}
-__x = __default;
 __context.exports(function() {
     return {
-        "default": __x
+        get "default" () {
+            return __default;
+        }
     };
 });
-var __x;
-export { __x as default };
+const __default = Object.defineProperty(class {
+}, "name", {
+    value: "default",
+    configurable: true
+});
+export { __default as default };
@@ -1,10 +1,10 @@
 const __context = global.__modules.register("1000");
-const __default = 'default';
-__x = __default;
 __context.exports(function() {
     return {
-        "default": __x
+        get "default" () {
+            return __default;
+        }
     };
 });
-var __x;
-export { __x as default };
+const __default = 'default';
+export { __default as default };
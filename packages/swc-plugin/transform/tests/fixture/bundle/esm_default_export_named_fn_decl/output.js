@@ -0,0 +1,8 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        "default": Component
+    };
+});
+function Component() {}
+export { Component as default };
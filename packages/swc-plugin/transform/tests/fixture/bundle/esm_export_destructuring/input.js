@@ -0,0 +1,7 @@
+const obj = { a: 1, b: { c: 2 }, d: 3, e: 4 };
+const arr = [1, 2, 3];
+
+export const { a, b: { c }, d: renamed = 0, ...rest } = obj;
+export const [x, , y = 2, ...others] = arr;
+export let first = 1, second = 2;
+export var third;
@@ -1,23 +1,34 @@
 const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "lazy" () {
+            return lazy;
+        },
+        get "foo" () {
+            return foo;
+        },
+        get "bar" () {
+            return bar;
+        },
+        get "named" () {
+            return baz;
+        },
+        get "variable" () {
+            return variable;
+        },
+        get "Class" () {
+            return Class;
+        },
+        "func": func
+    };
+});
 const foo = 'foo';
 const bar = 'bar';
 var lazy;
 lazy = 'lazy';
+// Export named (with declaration)
 const variable = 1;
 class Class {
 }
 function func() {}
-__x = lazy, __x1 = foo, __x2 = bar, __x3 = baz, __x4 = variable, __x5 = Class, __x6 = func;
-__context.exports(function() {
-    return {
-        "lazy": __x,
-        "foo": __x1,
-        "bar": __x2,
-        "named": __x3,
-        "variable": __x4,
-        "Class": __x5,
-        "func": __x6
-    };
-});
-var __x, __x1, __x2, __x3, __x4, __x5, __x6;
-export { __x as lazy, __x1 as foo, __x2 as bar, __x3 as named, __x4 as variable, __x5 as Class, __x6 as func };
+export { lazy as lazy, foo as foo, bar as bar, baz as named, variable as variable, Class as Class, func as func };
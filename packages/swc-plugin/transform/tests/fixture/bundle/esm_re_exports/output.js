@@ -5,14 +5,28 @@ import * as __mod3 from "./mod-4";
 const __context = global.__modules.register("1000");
 __context.exports(function() {
     return {
-        ...__context.exports.ns(__mod),
-        "mod2": __context.exports.ns(__mod1),
-        foo: __mod2.foo,
-        bar: __mod2.bar,
-        baz: __mod2.baz,
-        default: __mod3.default
+        get "foo" () {
+            return __mod2.foo;
+        },
+        get "bar" () {
+            return __mod2.bar;
+        },
+        get "baz" () {
+            return __mod2.baz;
+        },
+        get "default" () {
+            return __mod3.default;
+        }
     };
 });
+__context.exports(function() {
+    return {
+        "mod2": __mod1
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
 export * from './mod-1';
 export * as mod2 from './mod-2';
 export { foo, bar, baz } from './mod-3';
@@ -0,0 +1,23 @@
+import * as __mod from "./star";
+import * as __mod1 from "./a";
+import * as __mod2 from "./b";
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __mod1.default;
+        },
+        get "aDefault" () {
+            return __mod2.default;
+        },
+        get "y" () {
+            return __mod2.x;
+        }
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
+export * from './star';
+export { default } from './a';
+export { default as aDefault, x as y } from './b';
@@ -0,0 +1,30 @@
+import * as __mod from "./star";
+import * as __mod1 from "./ns";
+import * as __mod2 from "./ns";
+import * as __mod3 from "./named";
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "foo" () {
+            return __mod3.foo;
+        },
+        get "bar" () {
+            return bar;
+        }
+    };
+});
+__context.exports(function() {
+    return {
+        "default": __mod1,
+        "ns": __mod2
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
+const bar = 1;
+export * from './star';
+export * as default from './ns';
+export * as ns from './ns';
+export { foo } from './named';
+export { bar as bar };
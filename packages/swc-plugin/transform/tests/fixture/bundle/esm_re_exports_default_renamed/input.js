@@ -0,0 +1,3 @@
+export * from './star-1';
+export * from './star-2';
+export { x as default } from './a';
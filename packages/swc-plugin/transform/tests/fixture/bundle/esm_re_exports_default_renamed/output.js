@@ -0,0 +1,17 @@
+import * as __mod from "./star-1";
+import * as __mod1 from "./star-2";
+import * as __mod2 from "./a";
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __mod2.x;
+        }
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod, __mod1);
+});
+export * from './star-1';
+export * from './star-2';
+export { x as default } from './a';
@@ -0,0 +1,5 @@
+export * from './foo';
+export * from './bar';
+export * from './baz';
+export { default } from './foo';
+export const shared = 'explicit';
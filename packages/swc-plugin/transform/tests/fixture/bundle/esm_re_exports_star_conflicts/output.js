@@ -0,0 +1,24 @@
+import * as __mod from "./foo";
+import * as __mod1 from "./bar";
+import * as __mod2 from "./baz";
+import * as __mod3 from "./foo";
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __mod3.default;
+        },
+        get "shared" () {
+            return shared;
+        }
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod, __mod1, __mod2);
+});
+const shared = 'explicit';
+export * from './foo';
+export * from './bar';
+export * from './baz';
+export { default } from './foo';
+export { shared as shared };
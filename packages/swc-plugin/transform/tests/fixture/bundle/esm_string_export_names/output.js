@@ -0,0 +1,32 @@
+import { "a-b" as ab, "default" as def } from './foo';
+import * as __mod from "./bar";
+import * as __mod1 from "./baz";
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "some-name" () {
+            return value;
+        },
+        get "🍎" () {
+            return ab;
+        },
+        get "cd" () {
+            return __mod["c-d"];
+        },
+        get "g-h" () {
+            return __mod["e-f"];
+        },
+        get "i j" () {
+            return __mod.default;
+        }
+    };
+});
+__context.exports(function() {
+    return {
+        "k-l": __mod1
+    };
+});
+const value = 1;
+export { "c-d" as cd, "e-f" as "g-h", default as "i j" } from './bar';
+export * as "k-l" from './baz';
+export { value as "some-name", ab as "🍎" };
@@ -0,0 +1,38 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "self" () {
+            return self;
+        },
+        get "arrow" () {
+            return arrow;
+        },
+        "fn": fn,
+        get "Foo" () {
+            return Foo;
+        },
+        get "obj" () {
+            return obj;
+        }
+    };
+});
+const self = this;
+const arrow = ()=>this;
+function fn() {
+    return this;
+}
+class Foo extends (this ?? Object) {
+    bar = this;
+    static baz() {
+        return this;
+    }
+}
+const obj = {
+    get value () {
+        return this;
+    },
+    method () {
+        return this;
+    }
+};
+export { self as self, arrow as arrow, fn as fn, Foo as Foo, obj as obj };
@@ -0,0 +1,26 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "url" () {
+            return url;
+        },
+        get "env" () {
+            return env;
+        },
+        get "foo" () {
+            return foo;
+        },
+        get "dynamic" () {
+            return dynamic;
+        },
+        get "image" () {
+            return image;
+        }
+    };
+});
+const url = import.meta.url;
+const env = import.meta.env;
+const foo = import.meta.resolve('./foo');
+const dynamic = (name1)=>import.meta.resolve(name1);
+const image = new URL(`./${name}.png`, import.meta.url);
+export { url as url, env as env, foo as foo, dynamic as dynamic, image as image };
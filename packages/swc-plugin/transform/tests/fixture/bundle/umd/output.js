@@ -0,0 +1,17 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+(function(root, factory) {
+    if ("undefined" === 'function' && define.amd) {
+        define([
+            './dep'
+        ], factory);
+    } else if (typeof module === 'object' && module.exports) {
+        module.exports = __context.module.exports = factory(require('./dep'));
+    } else {
+        root.Lib = factory(root.Dep);
+    }
+})(this, function(dep) {
+    return {
+        dep
+    };
+});
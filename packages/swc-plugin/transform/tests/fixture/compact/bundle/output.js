@@ -0,0 +1,51 @@
+import { foo } from './foo';
+import * as baz from './baz';
+import * as __mod from "./re-exp";
+import * as __mod1 from "./re-exp-2";
+import * as __mod2 from "./re-exp-4";
+const __context = global.__modules.register("1000");
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
+__context.exports(function() {
+    return {
+        get variable () {
+            return variable;
+        },
+        get counter () {
+            return counter;
+        },
+        get Class () {
+            return Class;
+        },
+        func,
+        default: __default,
+        get value2 () {
+            return value;
+        },
+        get foo () {
+            return foo;
+        },
+        get baz2 () {
+            return baz;
+        },
+        get rx () {
+            return __mod1;
+        },
+        get rx4 () {
+            return __mod2.rx3;
+        }
+    };
+}, __mod);
+const value = 'val';
+const variable = 1;
+let counter = 0;
+class Class {
+}
+function func() {}
+function __default() {}
+export * from './re-exp';
+export * as rx from './re-exp-2';
+export { rx3 as rx4 } from './re-exp-4';
+export { variable, counter, Class, func, __default as default, value as value2, foo, baz as baz2 };
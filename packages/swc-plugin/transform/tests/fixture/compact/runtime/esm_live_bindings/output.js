@@ -0,0 +1,32 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get counter () {
+            return counter;
+        },
+        get flag () {
+            return flag;
+        },
+        get fixed () {
+            return fixed;
+        },
+        increment,
+        get total () {
+            return total;
+        },
+        get sum () {
+            return total;
+        }
+    };
+});
+let counter = 0;
+var flag;
+const fixed = 1;
+let total = 0;
+function increment() {
+    counter++;
+    total += 1;
+    [flag] = [
+        true
+    ];
+}
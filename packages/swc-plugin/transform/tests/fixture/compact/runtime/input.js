@@ -0,0 +1,17 @@
+import { foo } from './foo';
+import * as baz from './baz';
+
+const value = 'val';
+
+export const variable = 1;
+export let counter = 0;
+export class Class {}
+export function func() {}
+export default function () {}
+
+export { value as value2 };
+export { foo, baz as baz2 };
+
+export * from './re-exp';
+export * as rx from './re-exp-2';
+export { rx3 as rx4 } from './re-exp-4';
@@ -0,0 +1,47 @@
+const __context = global.__modules.register("1000");
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
+const { foo } = global.__modules.require("./foo");
+const { baz } = global.__modules.require("./baz");
+const __mod = global.__modules.require("./re-exp");
+const __mod1 = global.__modules.require("./re-exp-2");
+const __mod2 = global.__modules.require("./re-exp-4");
+__context.exports(function() {
+    return {
+        get variable () {
+            return variable;
+        },
+        get counter () {
+            return counter;
+        },
+        get Class () {
+            return Class;
+        },
+        func,
+        default: __default,
+        get value2 () {
+            return value;
+        },
+        get foo () {
+            return foo;
+        },
+        get baz2 () {
+            return baz;
+        },
+        get rx () {
+            return __mod1;
+        },
+        get rx4 () {
+            return __mod2.rx3;
+        }
+    };
+}, __mod);
+const value = 'val';
+const variable = 1;
+let counter = 0;
+class Class {
+}
+function func() {}
+function __default() {}
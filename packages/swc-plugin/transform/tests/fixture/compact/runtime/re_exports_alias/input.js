@@ -0,0 +1,3 @@
+export const version = 1;
+export { foo } from './foo';
+export * as utils from './utils';
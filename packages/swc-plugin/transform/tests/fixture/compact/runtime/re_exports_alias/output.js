@@ -0,0 +1,17 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get version () {
+            return version;
+        },
+        get foo () {
+            return __mod.foo;
+        },
+        get utils () {
+            return __mod1;
+        }
+    };
+});
+const __mod = global.__modules.require("./foo");
+const __mod1 = global.__modules.require("./utils");
+const version = 1;
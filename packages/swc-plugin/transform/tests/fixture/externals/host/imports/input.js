@@ -0,0 +1,7 @@
+import fs, { readFile } from 'fs';
+import * as path from 'node:path';
+import { foo } from './foo';
+
+const util = await import('node:util');
+
+console.log(fs, readFile, path, foo, util);
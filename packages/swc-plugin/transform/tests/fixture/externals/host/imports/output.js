@@ -0,0 +1,6 @@
+const __context = global.__modules.register("1000");
+const { default: fs, readFile } = global.__host.require("fs");
+const { path } = global.__host.require("node:path");
+const { foo } = global.__modules.require("./foo");
+const util = await global.__host.import("node:util");
+console.log(fs, readFile, path, foo, util);
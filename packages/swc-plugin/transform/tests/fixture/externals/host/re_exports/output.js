@@ -0,0 +1,23 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "Emitter" () {
+            return __mod1.EventEmitter;
+        },
+        get "bar" () {
+            return __mod3.bar;
+        }
+    };
+});
+const __mod = global.__host.require("node:events");
+const __mod1 = global.__host.require("node:events");
+const __mod2 = global.__host.require("fs");
+const __mod3 = global.__modules.require("./bar");
+__context.exports(function() {
+    return {
+        "fs": __mod2
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
@@ -0,0 +1,6 @@
+import fs, { readFile } from 'fs';
+import * as path from 'node:path';
+const __context = global.__modules.register("1000");
+const { foo } = global.__modules.require("./foo");
+const util = await import('node:util');
+console.log(fs, readFile, path, foo, util);
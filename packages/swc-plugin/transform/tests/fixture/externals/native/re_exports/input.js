@@ -0,0 +1,4 @@
+export * from 'node:events';
+export { EventEmitter as Emitter } from 'node:events';
+export * as fs from 'fs';
+export { bar } from './bar';
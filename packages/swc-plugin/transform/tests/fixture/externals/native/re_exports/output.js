@@ -0,0 +1,23 @@
+import * as __mod from "node:events";
+import * as __mod1 from "node:events";
+import * as __mod2 from "fs";
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "Emitter" () {
+            return __mod1.EventEmitter;
+        },
+        get "bar" () {
+            return __mod3.bar;
+        }
+    };
+});
+const __mod3 = global.__modules.require("./bar");
+__context.exports(function() {
+    return {
+        "fs": __mod2
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
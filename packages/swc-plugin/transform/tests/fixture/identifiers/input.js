@@ -0,0 +1,10 @@
+import { foo } from './foo';
+
+// User-declared global that collides with the configured context identifier
+__ctx.log(foo);
+
+const __exp = 'user binding';
+
+export const value = __exp;
+export default function () {}
+export * from './bar';
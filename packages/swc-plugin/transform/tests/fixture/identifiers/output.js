@@ -0,0 +1,23 @@
+const ___ctx = global.__modules.register("1000");
+Object.defineProperty(__def, "name", {
+    value: "default",
+    configurable: true
+});
+___ctx.exports(function() {
+    return {
+        get "value" () {
+            return value;
+        },
+        "default": __def
+    };
+});
+const { foo } = global.__modules.require("./foo");
+const __m = global.__modules.require("./bar");
+___ctx.exports(function() {
+    return ___ctx.exports.ns(__m);
+});
+// User-declared global that collides with the configured context identifier
+__ctx.log(foo);
+const __exp = 'user binding';
+const value = __exp;
+function __def() {}
@@ -0,0 +1,5 @@
+export const url = import.meta.url;
+export const env = import.meta.env;
+export const foo = import.meta.resolve('./foo');
+export const dynamic = (name) => import.meta.resolve(name);
+export const image = new URL(`./${name}.png`, import.meta.url);
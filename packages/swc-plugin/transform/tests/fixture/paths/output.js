@@ -1,5 +1,6 @@
+const ___context = global.__modules.register("1000");
+___context.module.exports = module.exports;
 const __context = global.__modules.register("1000");
-const __context1 = global.__modules.register("1000");
 const { default: React, useState, useCallback } = global.__modules.require("1000");
 const { foo } = global.__modules.require("1001");
 const { bar: bar2 } = global.__modules.require("1002");
@@ -15,9 +16,9 @@ if (__DEV__) {
     global.__modules.require("1005");
 }
 const value = 'val';
-module.exports = __context.module.exports = __context1.module.exports = 'cjs';
-module.exports.foo = __context.module.exports.foo = __context1.module.exports.foo = 2;
-Object.assign(module.exports = __context.module.exports = __context1.module.exports, {
+module.exports = ___context.module.exports = __context.module.exports = 'cjs';
+module.exports.foo = __context.module.exports.foo = 2;
+Object.assign(module.exports = ___context.module.exports = __context.module.exports, {
     bar: 1
 });
 const variable = 1;
@@ -32,7 +33,7 @@ function __default() {
     };
 }
 __x = variable, __x1 = Class, __x2 = func, __x3 = __default, __x4 = value, __x5 = foo, __x6 = foo2, __x7 = baz, __x8 = baz;
-__context1.exports(function() {
+__context.exports(function() {
     return {
         "variable": __x,
         "Class": __x1,
@@ -43,8 +44,8 @@ __context1.exports(function() {
         "foo2": __x6,
         "baz": __x7,
         "baz2": __x8,
-        ...__context1.exports.ns(__mod),
-        "rx": __context1.exports.ns(__mod1),
+        ...__context.exports.ns(__mod),
+        "rx": __context.exports.ns(__mod1),
         rx0: __mod2.rx0,
         rx1: __mod2.rx1,
         rx2: __mod2.rx2,
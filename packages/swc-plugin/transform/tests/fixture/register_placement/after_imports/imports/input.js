@@ -0,0 +1,8 @@
+import { child } from './child';
+import './side-effect';
+
+export const parent = 'parent';
+
+export default function App() {
+  return child;
+}
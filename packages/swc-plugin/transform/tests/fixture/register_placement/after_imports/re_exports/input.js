@@ -0,0 +1,5 @@
+import { foo } from './foo';
+export * from './bar';
+export { baz } from './baz';
+
+console.log(foo);
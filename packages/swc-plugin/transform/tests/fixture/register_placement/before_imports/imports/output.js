@@ -0,0 +1,16 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "parent" () {
+            return parent;
+        },
+        "default": App
+    };
+});
+import { child } from './child';
+import './side-effect';
+const parent = 'parent';
+function App() {
+    return child;
+}
+export { parent as parent, App as default };
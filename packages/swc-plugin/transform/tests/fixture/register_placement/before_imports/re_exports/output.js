@@ -0,0 +1,17 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "baz" () {
+            return __mod1.baz;
+        }
+    };
+});
+import { foo } from './foo';
+import * as __mod from "./bar";
+import * as __mod1 from "./baz";
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
+console.log(foo);
+export * from './bar';
+export { baz } from './baz';
@@ -1,4 +1,50 @@
 const __context = global.__modules.register("1000");
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
+__context.exports(function() {
+    return {
+        get "variable" () {
+            return variable;
+        },
+        get "Class" () {
+            return Class;
+        },
+        "func": func,
+        "default": __default,
+        get "value2" () {
+            return value;
+        },
+        get "foo" () {
+            return foo;
+        },
+        get "foo2" () {
+            return foo2;
+        },
+        get "baz" () {
+            return baz;
+        },
+        get "baz2" () {
+            return baz;
+        },
+        get "rx0" () {
+            return __mod2.rx0;
+        },
+        get "rx1" () {
+            return __mod2.rx1;
+        },
+        get "rx2" () {
+            return __mod2.rx2;
+        },
+        get "rx4" () {
+            return __mod3.rx3;
+        },
+        get "rx5" () {
+            return __mod4.default;
+        }
+    };
+});
 const { default: React, useState, useCallback } = global.__modules.require("react");
 const { foo } = global.__modules.require("./foo");
 const { bar: bar2 } = global.__modules.require("./bar");
@@ -9,14 +55,22 @@ const __mod1 = global.__modules.require("./re-exp-2");
 const __mod2 = global.__modules.require("./re-exp-3");
 const __mod3 = global.__modules.require("./re-exp-4");
 const __mod4 = global.__modules.require("./re-exp-5");
+__context.exports(function() {
+    return {
+        "rx": __mod1
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
 React.lazy(()=>global.__modules.import("./Component"));
 if (__DEV__) {
     global.__modules.require("./cjs-1");
 }
 const value = 'val';
-module.exports = __context.module.exports = 'cjs';
-module.exports.foo = __context.module.exports.foo = 2;
-Object.assign(module.exports = __context.module.exports, {
+module.exports = 'cjs';
+module.exports.foo = 2;
+Object.assign(module.exports, {
     bar: 1
 });
 const variable = 1;
@@ -30,23 +84,3 @@ function __default() {
         global.__modules.require("./cjs-3");
     };
 }
-__x = variable, __x1 = Class, __x2 = func, __x3 = __default, __x4 = value, __x5 = foo, __x6 = foo2, __x7 = baz, __x8 = baz;
-__context.exports(function() {
-    return {
-        "variable": __x,
-        "Class": __x1,
-        "func": __x2,
-        "default": __x3,
-        "value2": __x4,
-        "foo": __x5,
-        "foo2": __x6,
-        "baz": __x7,
-        "baz2": __x8,
-        ...__context.exports.ns(__mod),
-        "rx": __context.exports.ns(__mod1),
-        rx2: __mod2.rx2,
-        rx4: __mod3.rx3,
-        rx5: __mod4.default
-    };
-});
-var __x, __x1, __x2, __x3, __x4, __x5, __x6, __x7, __x8;
@@ -0,0 +1,4 @@
+define('named', ['./foo', 'exports', 'require'], function (foo, exports, require) {
+  exports.foo = foo;
+  exports.bar = require('./bar');
+});
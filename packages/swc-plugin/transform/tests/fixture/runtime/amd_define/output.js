@@ -0,0 +1,6 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+module.exports = __context.module.exports = function(foo, exports1, require) {
+    exports1.foo = foo;
+    exports1.bar = global.__modules.require("./bar");
+}(global.__modules.require("./foo"), exports, global.__modules.require) ?? module.exports;
@@ -0,0 +1,5 @@
+define(function (require, exports, module) {
+  const foo = require('./foo');
+
+  module.exports = { foo };
+});
@@ -0,0 +1,8 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+module.exports = __context.module.exports = function(require, exports1, module1) {
+    const foo = global.__modules.require("./foo");
+    module1.exports = {
+        foo
+    };
+}(global.__modules.require, exports, module) ?? module.exports;
@@ -0,0 +1 @@
+define({ foo: 'foo' });
@@ -0,0 +1,5 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+module.exports = __context.module.exports = {
+    foo: 'foo'
+};
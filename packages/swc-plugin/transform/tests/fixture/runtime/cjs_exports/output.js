@@ -1,13 +1,14 @@
 const __context = global.__modules.register("1000");
-exports.foo = __context.module.exports.foo = 'foo';
-exports.bar = __context.module.exports.bar = 'bar';
-exports.baz = __context.module.exports.baz = 'baz';
+__context.module.exports = module.exports;
+exports.foo = 'foo';
+exports.bar = 'bar';
+exports.baz = 'baz';
 module.exports = __context.module.exports = 'default';
-module.exports.foo = __context.module.exports.foo = 'foo';
-module.exports.bar = __context.module.exports.bar = 'bar';
-module.exports['baz'] = __context.module.exports.baz = 'baz';
-module.exports[global.export ? 'export_name' : '__hidden'] = __context.module.exports[global.export ? 'export_name' : '__hidden'] = 0;
-Object.assign(module.exports = __context.module.exports, {});
+module.exports.foo = 'foo';
+module.exports.bar = 'bar';
+module.exports['baz'] = 'baz';
+module.exports[global.export ? 'export_name' : '__hidden'] = 0;
+Object.assign(module.exports, {});
 function a(module1) {
     module1.exports.a = 'a';
     module1.exports.b = 'b';
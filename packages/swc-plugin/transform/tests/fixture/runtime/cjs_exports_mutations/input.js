@@ -0,0 +1,15 @@
+exports.count = 0;
+exports.count += 1;
+exports.count++;
+exports.list = [];
+exports.list.push(exports.count);
+exports.temp = 'temp';
+delete exports.temp;
+
+Object.defineProperty(exports, 'getter', {
+  enumerable: true,
+  get: () => exports.count,
+});
+
+module.exports.label ??= 'label';
+module.exports.label = module.exports.label.toUpperCase();
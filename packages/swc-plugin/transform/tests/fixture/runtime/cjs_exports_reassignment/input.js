@@ -0,0 +1,6 @@
+exports = module.exports = function main() {
+  return 'main';
+};
+
+exports.foo = 'foo';
+module.exports ||= {};
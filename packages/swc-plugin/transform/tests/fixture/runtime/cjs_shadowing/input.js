@@ -0,0 +1,34 @@
+const dep = require('./dep');
+
+function withRequire(require) {
+  return require('./local-1');
+}
+
+function withModule() {
+  const module = { exports: {} };
+  module.exports = 'local';
+
+  return function withExports(exports) {
+    exports.foo = 'local';
+
+    {
+      let require = () => null;
+      require('./local-2');
+    }
+
+    return () => require('./nested-dep');
+  };
+}
+
+try {
+  dep.run();
+} catch (exports) {
+  exports.handled = true;
+}
+
+const fn = (module) => module.exports;
+
+exports.dep = dep;
+module.exports.withRequire = withRequire;
+module.exports.withModule = withModule;
+module.exports.fn = fn;
@@ -0,0 +1,11 @@
+this.foo = 'foo';
+exports.self = this;
+
+const arrow = () => this;
+
+function fn() {
+  return this;
+}
+
+module.exports.fn = fn;
+module.exports.arrow = arrow;
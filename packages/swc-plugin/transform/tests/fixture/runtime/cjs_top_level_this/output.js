@@ -0,0 +1,10 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+__context.module.exports.foo = 'foo';
+exports.self = __context.module.exports;
+const arrow = ()=>__context.module.exports;
+function fn() {
+    return this;
+}
+module.exports.fn = fn;
+module.exports.arrow = arrow;
@@ -0,0 +1,25 @@
+/*! license: MIT */ /** @jsx h */ 'use client';
+'use strict';
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "bar" () {
+            return bar;
+        },
+        "baz": baz,
+        get "default" () {
+            return Qux;
+        }
+    };
+});
+const { h } = global.__modules.require("preact");
+const { foo } = global.__modules.require("./foo");
+/**
+ * Docs of `bar`.
+ */ const bar = foo;
+// Comment of `baz`
+function baz() {
+    return h('div');
+}
+/** Docs of the default class. */ class Qux {
+}
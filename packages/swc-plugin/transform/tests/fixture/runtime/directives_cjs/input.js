@@ -0,0 +1,6 @@
+/*! license: MIT */
+'use strict';
+const foo = require('./foo');
+
+/** Docs of the exports. */
+module.exports = foo;
@@ -0,0 +1,5 @@
+/*! license: MIT */ 'use strict';
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+const foo = global.__modules.require("./foo");
+/** Docs of the exports. */ module.exports = __context.module.exports = foo;
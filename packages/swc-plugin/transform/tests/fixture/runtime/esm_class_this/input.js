@@ -0,0 +1,26 @@
+const decorate = (value) => (target) => target;
+
+@decorate(this)
+export class Foo extends (this ?? Object) {
+  [this.method]() {
+    return this;
+  }
+
+  @decorate(this)
+  static [this.prop] = this;
+
+  #value = this;
+
+  static {
+    this.initialized = true;
+  }
+}
+
+export const obj = {
+  get [this.getter]() {
+    return this;
+  },
+  set [this.setter](value) {
+    this.value = value;
+  },
+};
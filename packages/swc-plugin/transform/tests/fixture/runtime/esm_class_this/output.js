@@ -0,0 +1,32 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "Foo" () {
+            return Foo;
+        },
+        get "obj" () {
+            return obj;
+        }
+    };
+});
+const decorate = (value1)=>(target)=>target;
+@decorate(void 0)
+class Foo extends ((void 0) ?? Object) {
+    [(void 0).method]() {
+        return this;
+    }
+    @decorate(void 0)
+    static [(void 0).prop] = this;
+    #value = this;
+    static{
+        this.initialized = true;
+    }
+}
+const obj = {
+    get [(void 0).getter] () {
+        return this;
+    },
+    set [(void 0).setter] (value){
+        this.value = value;
+    }
+};
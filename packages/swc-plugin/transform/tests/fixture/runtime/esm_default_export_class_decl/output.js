@@ -1,10 +1,13 @@
 const __context = global.__modules.register("1000");
-class __default {
-}
-__x = __default;
 __context.exports(function() {
     return {
-        "default": __x
+        get "default" () {
+            return __default;
+        }
     };
 });
-var __x;
+const __default = Object.defineProperty(class {
+}, "name", {
+    value: "default",
+    configurable: true
+});
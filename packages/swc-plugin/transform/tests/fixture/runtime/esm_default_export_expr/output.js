@@ -1,9 +1,9 @@
 const __context = global.__modules.register("1000");
-const __default = 'default';
-__x = __default;
 __context.exports(function() {
     return {
-        "default": __x
+        get "default" () {
+            return __default;
+        }
     };
 });
-var __x;
+const __default = 'default';
@@ -1,9 +1,11 @@
 const __context = global.__modules.register("1000");
-function __default() {}
-__x = __default;
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
 __context.exports(function() {
     return {
-        "default": __x
+        "default": __default
     };
 });
-var __x;
+function __default() {}
@@ -0,0 +1,12 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __default;
+        }
+    };
+});
+const __default = Object.defineProperty(function() {}, "name", {
+    value: "default",
+    configurable: true
+});
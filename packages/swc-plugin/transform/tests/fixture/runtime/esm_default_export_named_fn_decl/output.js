@@ -0,0 +1,7 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        "default": Component
+    };
+});
+function Component() {}
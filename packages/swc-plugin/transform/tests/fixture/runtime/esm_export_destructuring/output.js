@@ -0,0 +1,52 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "a" () {
+            return a;
+        },
+        get "c" () {
+            return c;
+        },
+        get "renamed" () {
+            return renamed;
+        },
+        get "rest" () {
+            return rest;
+        },
+        get "x" () {
+            return x;
+        },
+        get "y" () {
+            return y;
+        },
+        get "others" () {
+            return others;
+        },
+        get "first" () {
+            return first;
+        },
+        get "second" () {
+            return second;
+        },
+        get "third" () {
+            return third;
+        }
+    };
+});
+const obj = {
+    a: 1,
+    b: {
+        c: 2
+    },
+    d: 3,
+    e: 4
+};
+const arr = [
+    1,
+    2,
+    3
+];
+const { a, b: { c }, d: renamed = 0, ...rest } = obj;
+const [x, , y = 2, ...others] = arr;
+let first = 1, second = 2;
+var third;
@@ -0,0 +1,13 @@
+export let counter = 0;
+export var flag;
+export const fixed = 1;
+
+let total = 0;
+
+export function increment() {
+  counter++;
+  total += 1;
+  [flag] = [true];
+}
+
+export { total, total as sum };
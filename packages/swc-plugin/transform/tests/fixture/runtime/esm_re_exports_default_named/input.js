@@ -0,0 +1,3 @@
+export * from './star';
+export { default } from './a';
+export { default as aDefault, x as y } from './b';
@@ -0,0 +1,20 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __mod1.default;
+        },
+        get "aDefault" () {
+            return __mod2.default;
+        },
+        get "y" () {
+            return __mod2.x;
+        }
+    };
+});
+const __mod = global.__modules.require("./star");
+const __mod1 = global.__modules.require("./a");
+const __mod2 = global.__modules.require("./b");
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
@@ -0,0 +1,5 @@
+export * from './star';
+export * as default from './ns';
+export * as ns from './ns';
+export { foo } from './named';
+export const bar = 1;
@@ -0,0 +1,25 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "foo" () {
+            return __mod3.foo;
+        },
+        get "bar" () {
+            return bar;
+        }
+    };
+});
+const __mod = global.__modules.require("./star");
+const __mod1 = global.__modules.require("./ns");
+const __mod2 = global.__modules.require("./ns");
+const __mod3 = global.__modules.require("./named");
+__context.exports(function() {
+    return {
+        "default": __mod1,
+        "ns": __mod2
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod);
+});
+const bar = 1;
@@ -0,0 +1,14 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __mod2.x;
+        }
+    };
+});
+const __mod = global.__modules.require("./star-1");
+const __mod1 = global.__modules.require("./star-2");
+const __mod2 = global.__modules.require("./a");
+__context.exports(function() {
+    return __context.exports.ns(__mod, __mod1);
+});
@@ -0,0 +1,3 @@
+export { rx0, rx1, rx2 } from './re-exp';
+export { rx0 as renamed, default as rx3 } from './re-exp';
+export { default, rx4 as rx5 } from './re-exp-2';
@@ -0,0 +1,29 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "rx0" () {
+            return __mod.rx0;
+        },
+        get "rx1" () {
+            return __mod.rx1;
+        },
+        get "rx2" () {
+            return __mod.rx2;
+        },
+        get "renamed" () {
+            return __mod1.rx0;
+        },
+        get "rx3" () {
+            return __mod1.default;
+        },
+        get "default" () {
+            return __mod2.default;
+        },
+        get "rx5" () {
+            return __mod2.rx4;
+        }
+    };
+});
+const __mod = global.__modules.require("./re-exp");
+const __mod1 = global.__modules.require("./re-exp");
+const __mod2 = global.__modules.require("./re-exp-2");
@@ -0,0 +1,19 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "default" () {
+            return __mod3.default;
+        },
+        get "shared" () {
+            return shared;
+        }
+    };
+});
+const __mod = global.__modules.require("./foo");
+const __mod1 = global.__modules.require("./bar");
+const __mod2 = global.__modules.require("./baz");
+const __mod3 = global.__modules.require("./foo");
+__context.exports(function() {
+    return __context.exports.ns(__mod, __mod1, __mod2);
+});
+const shared = 'explicit';
@@ -0,0 +1,7 @@
+import { "a-b" as ab, "default" as def } from './foo';
+
+const value = 1;
+
+export { value as "some-name", ab as "🍎" };
+export { "c-d" as cd, "e-f" as "g-h", default as "i j" } from './bar';
+export * as "k-l" from './baz';
@@ -0,0 +1,29 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "some-name" () {
+            return value;
+        },
+        get "🍎" () {
+            return ab;
+        },
+        get "cd" () {
+            return __mod["c-d"];
+        },
+        get "g-h" () {
+            return __mod["e-f"];
+        },
+        get "i j" () {
+            return __mod.default;
+        }
+    };
+});
+const { "a-b": ab, default: def } = global.__modules.require("./foo");
+const __mod = global.__modules.require("./bar");
+const __mod1 = global.__modules.require("./baz");
+__context.exports(function() {
+    return {
+        "k-l": __mod1
+    };
+});
+const value = 1;
@@ -0,0 +1,23 @@
+export const self = this;
+export const arrow = () => this;
+
+export function fn() {
+  return this;
+}
+
+export class Foo extends (this ?? Object) {
+  bar = this;
+
+  static baz() {
+    return this;
+  }
+}
+
+export const obj = {
+  get value() {
+    return this;
+  },
+  method() {
+    return this;
+  },
+};
@@ -0,0 +1,37 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "self" () {
+            return self;
+        },
+        get "arrow" () {
+            return arrow;
+        },
+        "fn": fn,
+        get "Foo" () {
+            return Foo;
+        },
+        get "obj" () {
+            return obj;
+        }
+    };
+});
+const self = void 0;
+const arrow = ()=>void 0;
+function fn() {
+    return this;
+}
+class Foo extends ((void 0) ?? Object) {
+    bar = this;
+    static baz() {
+        return this;
+    }
+}
+const obj = {
+    get value () {
+        return this;
+    },
+    method () {
+        return this;
+    }
+};
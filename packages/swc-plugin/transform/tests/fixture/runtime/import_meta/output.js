@@ -0,0 +1,25 @@
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "url" () {
+            return url;
+        },
+        get "env" () {
+            return env;
+        },
+        get "foo" () {
+            return foo;
+        },
+        get "dynamic" () {
+            return dynamic;
+        },
+        get "image" () {
+            return image;
+        }
+    };
+});
+const url = __context.meta.url;
+const env = __context.meta.env;
+const foo = __context.meta.resolve("./foo");
+const dynamic = (name1)=>__context.meta.resolve(name1);
+const image = new URL(`./${name}.png`, __context.meta.url);
@@ -0,0 +1,11 @@
+(function (root, factory) {
+  if (typeof define === 'function' && define.amd) {
+    define(['./dep'], factory);
+  } else if (typeof module === 'object' && module.exports) {
+    module.exports = factory(require('./dep'));
+  } else {
+    root.Lib = factory(root.Dep);
+  }
+})(this, function (dep) {
+  return { dep };
+});
@@ -0,0 +1,11 @@
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' });
+const shared = new SharedWorker(new URL('./worker.js', import.meta.url));
+const logo = new URL('./logo.png', import.meta.url);
+
+// Not module relative URLs
+const external = new URL('https://example.com/logo.png');
+const relative = new URL('./logo.png', location.href);
+
+function local(URL) {
+  return new URL('./local.png', import.meta.url);
+}
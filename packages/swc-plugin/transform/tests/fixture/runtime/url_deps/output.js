@@ -0,0 +1,12 @@
+const __context = global.__modules.register("1000");
+const worker = new Worker(global.__modules.url("./worker.js"), {
+    type: 'module'
+});
+const shared = new SharedWorker(global.__modules.url("./worker.js"));
+const logo = global.__modules.url("./logo.png");
+// Not module relative URLs
+const external = new URL('https://example.com/logo.png');
+const relative = new URL('./logo.png', location.href);
+function local(URL1) {
+    return new URL1('./local.png', __context.meta.url);
+}
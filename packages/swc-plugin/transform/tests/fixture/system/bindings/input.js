@@ -0,0 +1,20 @@
+export let current;
+export let { length } = [];
+export var total = 0;
+
+for (current of [1, 2, 3]) total += current;
+
+for (current in { a: 1 }) {
+  console.log(current);
+}
+
+if (total > 0) {
+  var counted = total++;
+}
+
+export { counted };
+
+export function next() {
+  var local = total--;
+  return local;
+}
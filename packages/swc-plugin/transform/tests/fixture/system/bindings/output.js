@@ -0,0 +1,33 @@
+System.register("1000", [], function(_export, _context) {
+    var current, length, total, counted, __tmp;
+    function next() {
+        var local = (__tmp = total--, _export("total", total), __tmp);
+        return local;
+    }
+    _export("next", next);
+    return {
+        setters: [],
+        execute: function() {
+            _export("current", current);
+            ({ length } = []), _export("length", length);
+            _export("total", total = 0);
+            for (current of [
+                1,
+                2,
+                3
+            ]){
+                _export("current", current);
+                _export("total", total += current);
+            }
+            for(current in {
+                a: 1
+            }){
+                _export("current", current);
+                console.log(current);
+            }
+            if (total > 0) {
+                _export("counted", counted = (__tmp = total++, _export("total", total), __tmp));
+            }
+        }
+    };
+});
@@ -0,0 +1,30 @@
+/*! license: MIT */ /** @jsx h */ 'use client';
+'use strict';
+System.register("1000", [
+    "preact",
+    "./foo"
+], function(_export, _context) {
+    var h, foo, bar, Qux;
+    // Comment of `baz`
+    function baz() {
+        return h('div');
+    }
+    _export("baz", baz);
+    return {
+        setters: [
+            function(__mod) {
+                h = __mod.h;
+            },
+            function(__mod) {
+                foo = __mod.foo;
+            }
+        ],
+        execute: function() {
+            /**
+ * Docs of `bar`.
+ */ _export("bar", bar = foo);
+            /** Docs of the default class. */ _export("default", Qux = class Qux {
+            });
+        }
+    };
+});
@@ -0,0 +1,20 @@
+import { imported } from './foo';
+
+export let count = 0;
+export const obj = { value: 0 };
+export let [first, second] = [1, 2];
+export class Foo {}
+export default function () {}
+export { imported, count as counter };
+
+export function increment() {
+  count++;
+  ++count;
+  count += 1;
+}
+
+[first, second] = [second, first];
+
+function reset() {
+  count = 0;
+}
@@ -0,0 +1,43 @@
+System.register("1000", [
+    "./foo"
+], function(_export, _context) {
+    var imported, count, obj, first, second, Foo, __tmp;
+    function __default() {}
+    function increment() {
+        __tmp = count++, _export("counter", _export("count", count)), __tmp;
+        _export("counter", _export("count", ++count));
+        _export("counter", _export("count", count += 1));
+    }
+    function reset() {
+        _export("counter", _export("count", count = 0));
+    }
+    _export("default", __default);
+    _export("increment", increment);
+    return {
+        setters: [
+            function(__mod) {
+                _export("imported", imported = __mod.imported);
+            }
+        ],
+        execute: function() {
+            Object.defineProperty(__default, "name", {
+                value: "default",
+                configurable: true
+            });
+            _export("counter", _export("count", count = 0));
+            _export("obj", obj = {
+                value: 0
+            });
+            [first, second] = [
+                1,
+                2
+            ], _export("first", first), _export("second", second);
+            _export("Foo", Foo = class Foo {
+            });
+            [first, second] = [
+                second,
+                first
+            ], _export("first", first), _export("second", second);
+        }
+    };
+});
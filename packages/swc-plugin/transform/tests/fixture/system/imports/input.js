@@ -0,0 +1,10 @@
+import 'side-effect';
+import React, { useState, useMemo as useMemoization } from 'react';
+import * as all from 'mod-1';
+import { foo } from 'mod-2';
+import { bar } from 'mod-2';
+
+console.log(React, useState, useMemoization, all, foo, bar);
+console.log(import.meta.url);
+
+import('./lazy').then((mod) => console.log(mod));
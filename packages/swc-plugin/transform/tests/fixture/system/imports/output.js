@@ -0,0 +1,30 @@
+System.register("1000", [
+    "side-effect",
+    "react",
+    "mod-1",
+    "mod-2"
+], function(_export, _context) {
+    var React, useState, useMemoization, all, foo, bar;
+    return {
+        setters: [
+            function(__mod) {},
+            function(__mod) {
+                React = __mod.default;
+                useState = __mod.useState;
+                useMemoization = __mod.useMemo;
+            },
+            function(__mod) {
+                all = __mod;
+            },
+            function(__mod) {
+                foo = __mod.foo;
+                bar = __mod.bar;
+            }
+        ],
+        execute: function() {
+            console.log(React, useState, useMemoization, all, foo, bar);
+            console.log(_context.meta.url);
+            _context.import("./lazy").then((mod)=>console.log(mod));
+        }
+    };
+});
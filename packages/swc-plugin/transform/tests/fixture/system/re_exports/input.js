@@ -0,0 +1,5 @@
+export * from './star-1';
+export * from './star-2';
+export * as ns from './star-1';
+export { default, foo as bar } from './named';
+export const baz = 'explicit';
@@ -0,0 +1,35 @@
+System.register("1000", [
+    "./star-1",
+    "./star-2",
+    "./named"
+], function(_export, _context) {
+    var baz;
+    return {
+        setters: [
+            function(__mod) {
+                _export("ns", __mod);
+                for(var __key in __mod)if ([
+                    "default",
+                    "ns",
+                    "bar",
+                    "baz"
+                ].indexOf(__key) < 0) _export(__key, __mod[__key]);
+            },
+            function(__mod) {
+                for(var __key in __mod)if ([
+                    "default",
+                    "ns",
+                    "bar",
+                    "baz"
+                ].indexOf(__key) < 0) _export(__key, __mod[__key]);
+            },
+            function(__mod) {
+                _export("default", __mod.default);
+                _export("bar", __mod.foo);
+            }
+        ],
+        execute: function() {
+            _export("baz", baz = 'explicit');
+        }
+    };
+});
@@ -0,0 +1,3 @@
+import { load } from './loader';
+
+export const data = await load();
@@ -0,0 +1,15 @@
+System.register("1000", [
+    "./loader"
+], function(_export, _context) {
+    var load, data;
+    return {
+        setters: [
+            function(__mod) {
+                load = __mod.load;
+            }
+        ],
+        execute: async function() {
+            _export("data", data = await load());
+        }
+    };
+});
@@ -0,0 +1,30 @@
+const __context = global.__modules.register("1000");
+__context.module.exports = module.exports;
+const dep = global.__modules.require("./dep");
+function withRequire(require) {
+    return require('./local-1');
+}
+function withModule() {
+    const module1 = {
+        exports: {}
+    };
+    module1.exports = 'local';
+    return function withExports(exports1) {
+        exports1.foo = 'local';
+        {
+            let require = ()=>null;
+            require('./local-2');
+        }
+        return ()=>global.__modules.require("./nested-dep");
+    };
+}
+try {
+    dep.run();
+} catch (exports1) {
+    exports1.handled = true;
+}
+const fn = (module1)=>module1.exports;
+exports.dep = dep;
+module.exports.withRequire = withRequire;
+module.exports.withModule = withModule;
+module.exports.fn = fn;
@@ -0,0 +1,7 @@
+export const foo = 1;
+export const bar = 2;
+export function baz() {}
+export default function () {}
+export { qux, quux } from './qux';
+export * as ns from './ns';
+export * from './all';
@@ -0,0 +1,29 @@
+import * as __mod from "./qux";
+import * as __mod1 from "./ns";
+import * as __mod2 from "./all";
+const __context = global.__modules.register("1000");
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
+__context.exports(function() {
+    return {
+        get "foo" () {
+            return foo;
+        },
+        get "qux" () {
+            return __mod.qux;
+        }
+    };
+});
+__context.exports(function() {
+    return __context.exports.ns(__mod2);
+});
+const foo = 1;
+const bar = 2;
+function baz() {}
+function __default() {}
+export { qux, quux } from './qux';
+export * as ns from './ns';
+export * from './all';
+export { foo as foo, bar as bar, baz as baz, __default as default };
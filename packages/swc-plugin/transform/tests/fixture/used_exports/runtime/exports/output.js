@@ -0,0 +1,25 @@
+const __context = global.__modules.register("1000");
+Object.defineProperty(__default, "name", {
+    value: "default",
+    configurable: true
+});
+__context.exports(function() {
+    return {
+        get "foo" () {
+            return foo;
+        },
+        get "qux" () {
+            return __mod.qux;
+        }
+    };
+});
+const __mod = global.__modules.require("./qux");
+const __mod1 = global.__modules.require("./ns");
+const __mod2 = global.__modules.require("./all");
+__context.exports(function() {
+    return __context.exports.ns(__mod2);
+});
+const foo = 1;
+const bar = 2;
+function baz() {}
+function __default() {}
@@ -0,0 +1,139 @@
+use swc_core::{
+    common::{
+        collections::AHashMap, sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext,
+        GLOBALS,
+    },
+    ecma::{ast::Program, transforms::base::resolver, visit::VisitMutWith},
+};
+use swc_ecma_parser::{parse_file_as_program, EsSyntax, Syntax};
+use swc_global_modules::{collect_deps, plan_update, ModuleGraph, UpdatePlan};
+
+fn graph(modules: &[(&str, &[&str])]) -> ModuleGraph {
+    let mut graph = ModuleGraph::new();
+
+    for (id, deps) in modules {
+        graph.add_module(
+            id.to_string(),
+            deps.iter().map(|dep| dep.to_string()).collect(),
+        );
+    }
+
+    graph
+}
+
+fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn collect_deps_from_program() {
+    GLOBALS.set(&Globals::new(), || {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+            Lrc::new(FileName::Anon),
+            r#"
+            import React from 'react';
+            import './side-effect';
+            import { foo } from './foo';
+            export * from './bar';
+            export { baz } from './baz';
+            require('./foo');
+            import('./lazy');
+            "#
+            .into(),
+        );
+        let mut program: Program = parse_file_as_program(
+            &fm,
+            Syntax::Es(EsSyntax::default()),
+            Default::default(),
+            None,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let unresolved_mark = Mark::new();
+
+        program.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+
+        let mut paths = AHashMap::default();
+        paths.insert(String::from("react"), String::from("1000"));
+        paths.insert(String::from("./foo"), String::from("1001"));
+
+        assert_eq!(
+            collect_deps(
+                &program,
+                &Some(paths),
+                SyntaxContext::empty().apply_mark(unresolved_mark),
+            ),
+            ids(&["1000", "./side-effect", "1001", "./lazy", "./bar", "./baz"]),
+        );
+    });
+}
+
+#[test]
+fn update_until_boundary() {
+    let mut graph = graph(&[
+        ("entry", &["app"]),
+        ("app", &["button", "theme"]),
+        ("button", &["theme"]),
+        ("theme", &[]),
+    ]);
+    graph.set_accept("app", true);
+
+    assert_eq!(
+        plan_update(&graph, &ids(&["theme"])),
+        UpdatePlan::Update {
+            modules: ids(&["theme", "button", "app"]),
+            boundaries: ids(&["app"]),
+        }
+    );
+}
+
+#[test]
+fn self_accepting_module() {
+    let mut graph = graph(&[("entry", &["app"]), ("app", &[])]);
+    graph.set_accept("app", true);
+
+    assert_eq!(
+        plan_update(&graph, &ids(&["app"])),
+        UpdatePlan::Update {
+            modules: ids(&["app"]),
+            boundaries: ids(&["app"]),
+        }
+    );
+}
+
+#[test]
+fn full_reload_without_boundary() {
+    let graph = graph(&[("entry", &["app"]), ("app", &[])]);
+
+    assert_eq!(plan_update(&graph, &ids(&["app"])), UpdatePlan::FullReload);
+}
+
+#[test]
+fn full_reload_for_unknown_module() {
+    let graph = graph(&[("entry", &[])]);
+
+    assert_eq!(
+        plan_update(&graph, &ids(&["unknown"])),
+        UpdatePlan::FullReload
+    );
+}
+
+#[test]
+fn circular_dependencies() {
+    let mut graph = graph(&[
+        ("entry", &["a"]),
+        ("a", &["b"]),
+        ("b", &["a", "c"]),
+        ("c", &[]),
+    ]);
+    graph.set_accept("a", true);
+
+    assert_eq!(
+        plan_update(&graph, &ids(&["c"])),
+        UpdatePlan::Update {
+            modules: ids(&["c", "b", "a"]),
+            boundaries: ids(&["a"]),
+        }
+    );
+}
@@ -0,0 +1,55 @@
+use swc_global_modules::{transform, Identifiers, ModuleSource, Options};
+
+fn transform_with(identifiers: Identifiers) -> Result<String, Vec<String>> {
+    transform(
+        &ModuleSource::new("1000", "index.js", "export const foo = 1;"),
+        true,
+        &None,
+        &Options {
+            identifiers,
+            ..Default::default()
+        },
+    )
+    .map(|output| output.code)
+    .map_err(|error| error.messages)
+}
+
+#[test]
+fn accept_valid_identifiers() {
+    let code = transform_with(Identifiers {
+        context: String::from("$ctx"),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert!(code.contains("const $ctx = global.__modules.register(\"1000\");"));
+}
+
+#[test]
+fn reject_invalid_identifiers() {
+    assert_eq!(
+        transform_with(Identifiers {
+            context: String::from("my-context"),
+            ..Default::default()
+        })
+        .unwrap_err(),
+        vec!["invalid identifier name of `context`: 'my-context'"]
+    );
+    assert_eq!(
+        transform_with(Identifiers {
+            module: String::from("import"),
+            ..Default::default()
+        })
+        .unwrap_err(),
+        vec!["invalid identifier name of `module`: 'import'"]
+    );
+}
+
+#[test]
+fn reject_unknown_identifiers() {
+    let error = serde_json::from_str::<Options>(r#"{ "identifiers": { "binding": "__x" } }"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("unknown field `binding`"), "{}", error);
+}
@@ -0,0 +1,72 @@
+use swc_global_modules::{transform, ModuleKind, ModuleSource, Options};
+
+fn transform_code(code: &str) -> swc_global_modules::TransformOutput {
+    transform(
+        &ModuleSource::new("1000", "index.js", code),
+        true,
+        &None,
+        &Options::default(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn detect_esm() {
+    let output = transform_code("import foo from './foo';\nexport const bar = foo;");
+
+    assert_eq!(output.kind, ModuleKind::Esm);
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn detect_cjs() {
+    let output = transform_code("const foo = require('./foo');\nexports.bar = foo;");
+
+    assert_eq!(output.kind, ModuleKind::Cjs);
+    assert!(output.warnings.is_empty());
+    assert!(output
+        .code
+        .contains("__context.module.exports = module.exports;"));
+}
+
+#[test]
+fn detect_script_without_module_syntax() {
+    let output = transform_code("console.log(this);");
+
+    assert_eq!(output.kind, ModuleKind::Script);
+    assert!(!output.code.contains("module.exports = module.exports"));
+    // Top-level `this` of the plain script is kept
+    assert!(output.code.contains("console.log(this);"));
+}
+
+#[test]
+fn detect_cjs_with_require_calls() {
+    let output = transform_code("const foo = require('./foo');\nfoo(this);");
+
+    assert_eq!(output.kind, ModuleKind::Cjs);
+    assert!(output.code.contains("foo(__context.module.exports);"));
+}
+
+#[test]
+fn ignore_shadowed_cjs_references() {
+    let output = transform_code(
+        "export const foo = 1;\nfunction bar(module, exports) { module.exports = exports; }",
+    );
+
+    assert_eq!(output.kind, ModuleKind::Esm);
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn detect_mixed_and_warn() {
+    let output = transform_code("export const foo = 1;\nmodule.exports.bar = 2;");
+
+    assert_eq!(output.kind, ModuleKind::Mixed);
+    assert_eq!(
+        output.warnings,
+        vec!["mixed ESModule and CommonJS usage, CommonJS exports are not registered"]
+    );
+    // Transformed as ESModule
+    assert!(output.code.contains("__context.exports(function()"));
+    assert!(!output.code.contains("__context.module.exports"));
+}
@@ -0,0 +1,50 @@
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{transform, ModuleSource, Options, OutputFormat};
+
+fn system_options() -> Options {
+    Options {
+        format: OutputFormat::System,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn register_with_mapped_deps() {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./foo"), String::from("1001"));
+    paths.insert(String::from("./bar"), String::from("1002"));
+
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "import foo from './foo';\nexport * from './bar';\nimport('./foo');",
+        ),
+        true,
+        &Some(paths),
+        &system_options(),
+    )
+    .unwrap();
+
+    assert_eq!(output.deps, vec!["1001", "1002"]);
+    assert!(output
+        .code
+        .contains("System.register(\"1000\", [\n    \"1001\",\n    \"1002\"\n]"));
+    assert!(output.code.contains("_context.import(\"1001\")"));
+    assert!(!output.code.contains("global.__modules"));
+}
+
+#[test]
+fn reject_cjs_exports() {
+    let error = transform(
+        &ModuleSource::new("1000", "index.js", "module.exports = 1;"),
+        true,
+        &None,
+        &system_options(),
+    )
+    .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("CommonJS exports are not supported in the SystemJS format"));
+}
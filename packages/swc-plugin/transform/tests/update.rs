@@ -0,0 +1,132 @@
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{emit_update, Externals, ModuleSource, Options};
+
+#[test]
+fn update_chunk_ordered_by_deps() {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./Button"), String::from("1001"));
+    paths.insert(String::from("./theme"), String::from("1002"));
+
+    let chunk = emit_update(
+        &[
+            ModuleSource::new(
+                "1000",
+                "App.js",
+                "import { Button } from './Button';\nexport const App = () => Button();",
+            ),
+            ModuleSource::new(
+                "1001",
+                "Button.js",
+                "import { color } from './theme';\nexport function Button() { return color; }",
+            ),
+            ModuleSource::new("1002", "theme.js", "export const color = 'red';"),
+        ],
+        &Some(paths),
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(chunk.ids, vec!["1002", "1001", "1000"]);
+    testing::assert_eq!(
+        chunk.code,
+        r#"// @global-modules-update ["1002","1001","1000"]
+// 1002
+(function () {
+const __context = global.__modules.register("1002");
+__context.exports(function() {
+    return {
+        get "color" () {
+            return color;
+        }
+    };
+});
+const color = 'red';
+})();
+// 1001
+(function () {
+const __context = global.__modules.register("1001");
+__context.exports(function() {
+    return {
+        "Button": Button
+    };
+});
+const { color } = global.__modules.require("1002");
+function Button() {
+    return color;
+}
+})();
+// 1000
+(function () {
+const __context = global.__modules.register("1000");
+__context.exports(function() {
+    return {
+        get "App" () {
+            return App;
+        }
+    };
+});
+const { Button } = global.__modules.require("1001");
+const App = ()=>Button();
+})();
+"#
+    );
+}
+
+#[test]
+fn report_transform_errors() {
+    let error = emit_update(
+        &[ModuleSource::new("1000", "invalid.js", "export const = ;")],
+        &None,
+        &Options::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(error.filename, "invalid.js");
+    assert!(!error.messages.is_empty());
+}
+
+#[test]
+fn reject_top_level_await() {
+    let error = emit_update(
+        &[ModuleSource::new(
+            "1000",
+            "index.js",
+            "const config = await fetch('/config');\nexport default config;",
+        )],
+        &None,
+        &Options::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(error.filename, "index.js");
+    assert_eq!(
+        error.messages,
+        vec!["top-level await is not supported in update chunks"]
+    );
+}
+
+#[test]
+fn reject_externals_without_accessor() {
+    let error = emit_update(
+        &[ModuleSource::new(
+            "1000",
+            "index.js",
+            "import fs from 'node:fs';\nexport const foo = fs;",
+        )],
+        &None,
+        &Options {
+            externals: Externals {
+                names: vec![String::from("node:fs")],
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(error.filename, "index.js");
+    assert_eq!(
+        error.messages,
+        vec!["external dependency 'node:fs' requires `externals.accessor` in update chunks"]
+    );
+}
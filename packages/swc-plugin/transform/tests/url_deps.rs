@@ -0,0 +1,57 @@
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{transform, ModuleSource, Options, UrlDep, UrlDepKind};
+
+#[test]
+fn collect_worker_and_asset_deps() {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./worker.js"), String::from("1001"));
+    paths.insert(String::from("./logo.png"), String::from("1002"));
+
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "import './foo';\nnew Worker(new URL('./worker.js', import.meta.url));\nnew URL('./logo.png', import.meta.url);\nnew URL('./logo.png', import.meta.url);",
+        ),
+        true,
+        &Some(paths),
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(output.deps, vec!["./foo"]);
+    assert_eq!(
+        output.url_deps,
+        vec![
+            UrlDep {
+                src: String::from("1001"),
+                kind: UrlDepKind::Worker,
+            },
+            UrlDep {
+                src: String::from("1002"),
+                kind: UrlDepKind::Asset,
+            },
+        ]
+    );
+    assert!(output
+        .code
+        .contains("new Worker(global.__modules.url(\"1001\"))"));
+}
+
+#[test]
+fn ignore_non_relative_urls() {
+    let output = transform(
+        &ModuleSource::new(
+            "1000",
+            "index.js",
+            "new Worker('./worker.js');\nnew URL('./logo.png', location.href);\nnew URL(src, import.meta.url);",
+        ),
+        true,
+        &None,
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert!(output.url_deps.is_empty());
+    assert!(!output.code.contains("global.__modules.url"));
+}
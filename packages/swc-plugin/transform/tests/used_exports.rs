@@ -0,0 +1,126 @@
+use swc_core::common::collections::AHashMap;
+use swc_global_modules::{
+    transform, ExportUsage, ModuleGraph, ModuleSource, Options, ReExport, UsedExports,
+};
+
+fn paths() -> AHashMap<String, String> {
+    let mut paths = AHashMap::default();
+    paths.insert(String::from("./math"), String::from("1001"));
+    paths.insert(String::from("./utils"), String::from("1002"));
+    paths.insert(String::from("./barrel"), String::from("1003"));
+    paths.insert(String::from("./button"), String::from("1004"));
+    paths.insert(String::from("./side"), String::from("1005"));
+    paths
+}
+
+fn sources() -> Vec<ModuleSource> {
+    vec![
+        ModuleSource::new(
+            "1000",
+            "index.js",
+            "import { add } from './math';\nimport * as utils from './utils';\nimport { mul, Button } from './barrel';\nimport './side';\nexport { sub } from './math';\nconsole.log(add, utils, mul, Button);",
+        ),
+        ModuleSource::new(
+            "1001",
+            "math.js",
+            "export const add = 1;\nexport const sub = 2;\nexport const mul = 3;\nexport const div = 4;",
+        ),
+        ModuleSource::new("1002", "utils.js", "export const noop = 0;"),
+        ModuleSource::new(
+            "1003",
+            "barrel.js",
+            "export * from './math';\nexport { default as Button } from './button';",
+        ),
+        ModuleSource::new("1004", "button.js", "export default 0;\nexport const size = 1;"),
+        ModuleSource::new("1005", "side.js", "export const unused = 0;"),
+    ]
+}
+
+fn sorted(mut names: Vec<String>) -> Vec<String> {
+    names.sort();
+    names
+}
+
+#[test]
+fn collect_export_usage_of_module() {
+    let output = transform(&sources()[0], true, &Some(paths()), &Options::default()).unwrap();
+
+    assert_eq!(
+        output.export_usage,
+        ExportUsage {
+            imports: vec![
+                (
+                    String::from("1001"),
+                    UsedExports::Names(vec![String::from("add")])
+                ),
+                (String::from("1002"), UsedExports::All),
+                (
+                    String::from("1003"),
+                    UsedExports::Names(vec![String::from("mul"), String::from("Button")])
+                ),
+                (String::from("1005"), UsedExports::Names(vec![])),
+            ],
+            re_exports: vec![ReExport {
+                name: String::from("sub"),
+                src: String::from("1001"),
+                orig: Some(String::from("sub")),
+            }],
+            star_re_exports: vec![],
+        }
+    );
+}
+
+#[test]
+fn compute_used_exports_of_graph() {
+    let paths = Some(paths());
+    let mut graph = ModuleGraph::new();
+
+    for source in sources() {
+        let output = transform(&source, true, &paths, &Options::default()).unwrap();
+
+        graph.add_module(output.id.clone(), output.deps);
+        graph.set_export_usage(&output.id, output.export_usage);
+    }
+
+    let used_exports = graph.used_exports(&[String::from("1000")]);
+
+    assert_eq!(used_exports.len(), 4);
+    assert!(!used_exports.contains_key("1000"));
+    assert!(!used_exports.contains_key("1002"));
+    assert_eq!(
+        sorted(used_exports["1001"].clone()),
+        vec!["add", "mul", "sub"]
+    );
+    assert_eq!(sorted(used_exports["1003"].clone()), vec!["Button", "mul"]);
+    assert_eq!(used_exports["1004"], vec!["default"]);
+    assert!(used_exports["1005"].is_empty());
+}
+
+#[test]
+fn use_all_exports_without_usage() {
+    let mut graph = ModuleGraph::new();
+    graph.add_module(String::from("1000"), vec![String::from("1001")]);
+    graph.add_module(String::from("1001"), vec![]);
+
+    assert!(graph.used_exports(&[String::from("1000")]).is_empty());
+}
+
+#[test]
+fn register_used_exports_only() {
+    let mut used_exports = AHashMap::default();
+    used_exports.insert(String::from("1001"), vec![String::from("add")]);
+
+    let output = transform(
+        &sources()[1],
+        true,
+        &None,
+        &Options {
+            used_exports,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(output.code.contains("get \"add\" ()"));
+    assert!(!output.code.contains("get \"sub\" ()"));
+}
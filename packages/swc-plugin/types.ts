@@ -11,4 +11,70 @@ export interface PluginConfig {
    * The paths for mapping module sources.
    */
   paths?: Record<string, string>;
+  /**
+   * Compact output.
+   *
+   * Export properties and specifiers are emitted in their shortest form (eg. `{ foo, get bar() { ... } }`),
+   * and all exports are registered with a single exports call.
+   */
+  compact?: boolean;
+  /**
+   * The output format (default: `'globalModules'`).
+   *
+   * - `'globalModules'`: Registers the module to the global module registry.
+   * - `'system'`: Registers the module with SystemJS (`System.register(id, deps, declare)`).
+   */
+  format?: 'globalModules' | 'system';
+  /**
+   * The dependencies provided by the host (not registered to the global module registry).
+   */
+  externals?: {
+    /**
+     * Exact sources (eg. `'node:fs'`).
+     */
+    names?: string[];
+    /**
+     * Source patterns (regular expressions, eg. `'^node:'`).
+     */
+    patterns?: string[];
+    /**
+     * Host accessor that provides `require(src)` and `import(src)` (eg. `'global.__host'`).
+     *
+     * If not provided, the external imports are kept as they are in the runtime phase.
+     */
+    accessor?: string;
+  };
+  /**
+   * The exports used at runtime (key: module id).
+   *
+   * Only the listed exports are registered to the global module registry.
+   * Modules that are not listed register all of their exports.
+   */
+  usedExports?: Record<string, string[]>;
+  /**
+   * The placement of the register call in the bundle phase (default: `'afterImports'`).
+   *
+   * - `'afterImports'`: Registers the module after the import statements.
+   * - `'beforeImports'`: Registers the module (and its exports) before the import statements.
+   */
+  registerPlacement?: 'afterImports' | 'beforeImports';
+  /**
+   * The names of the generated identifiers.
+   *
+   * Names are used as prefixes and prefixed with `_` when they collide with identifiers in the source.
+   */
+  identifiers?: {
+    /**
+     * Module context identifier (default: `__context`).
+     */
+    context?: string;
+    /**
+     * Anonymous default export identifier (default: `__default`).
+     */
+    default?: string;
+    /**
+     * Re-exported module identifier (default: `__mod`).
+     */
+    module?: string;
+  };
 }